@@ -4,6 +4,8 @@ VERSION=$(date +%Y-%m-%d_%H-%M-%S)-$(git rev-parse --short HEAD)
 SRC=${PWD}
 DIR=${SRC}/release/${VERSION}
 
+cargo test --release --features enable_trace --test trace
+
 mkdir -p release
 mkdir ${DIR}
 mkdir ${DIR}/src
@@ -38,6 +40,18 @@ cp src/plan.rs ${DIR}/src
 cp src/line2.rs ${DIR}/src
 cp src/config.rs ${DIR}/src
 cp src/solid.rs ${DIR}/src
+cp src/zone.rs ${DIR}/src
+cp src/ball_predictor.rs ${DIR}/src
+cp src/reachability.rs ${DIR}/src
+cp src/learning.rs ${DIR}/src
+cp src/opponent_model.rs ${DIR}/src
+cp src/opponent_profile.rs ${DIR}/src
+cp src/kickoff.rs ${DIR}/src
+cp src/evaluation.rs ${DIR}/src
+cp src/assignment.rs ${DIR}/src
+cp src/nitro_economy.rs ${DIR}/src
+cp src/blackboard.rs ${DIR}/src
+cp src/fallback.rs ${DIR}/src
 
 cd ${DIR}/src
 
@@ -45,6 +59,7 @@ zip ${SRC}/release/${VERSION}.zip *.rs
 
 cp ${SRC}/src/examples.rs .
 cp ${SRC}/src/lib.rs .
+cp ${SRC}/src/training.rs .
 
 cd ..
 
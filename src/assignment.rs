@@ -0,0 +1,57 @@
+pub const INVALID_SCORE: i32 = -1_000_000;
+
+pub fn find_max_assignment(scores: &[Vec<i32>]) -> Option<(i32, Vec<usize>)> {
+    if scores.is_empty() {
+        return None;
+    }
+
+    let mut slots: Vec<usize> = (0..scores[0].len()).collect();
+    let mut result: Option<(i32, Vec<usize>)> = None;
+
+    loop {
+        let score = get_score(scores, &slots);
+
+        if result.as_ref().map(|(best, _)| score >= *best).unwrap_or(true) {
+            result = Some((score, slots[0..scores.len()].to_vec()));
+        }
+
+        if !next_permutation(&mut slots) {
+            break;
+        }
+    }
+
+    result
+}
+
+pub fn get_score(scores: &[Vec<i32>], slots: &[usize]) -> i32 {
+    scores.iter().zip(slots.iter())
+        .map(|(v, slot)| v[*slot])
+        .sum()
+}
+
+pub fn next_permutation<T: Ord>(values: &mut [T]) -> bool {
+    if values.len() < 2 {
+        return false;
+    }
+
+    let mut i = values.len() - 1;
+
+    while i > 0 && values[i - 1] >= values[i] {
+        i -= 1;
+    }
+
+    if i == 0 {
+        return false;
+    }
+
+    let mut j = values.len() - 1;
+
+    while values[j] <= values[i - 1] {
+        j -= 1;
+    }
+
+    values.swap(i - 1, j);
+    values[i..].reverse();
+
+    true
+}
@@ -0,0 +1,136 @@
+use crate::my_strategy::vec3::Vec3;
+use crate::my_strategy::world::World;
+use crate::my_strategy::simulator::{Simulator, BallCollisionType};
+
+#[derive(Debug, Clone)]
+pub struct BallPredictor {
+    tick: i32,
+    micro_ticks_per_tick: usize,
+    states: Vec<BallState>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BallState {
+    pub position: Vec3,
+    pub velocity: Vec3,
+    pub collision_type: BallCollisionType,
+    pub score: i32,
+}
+
+impl BallPredictor {
+    pub fn new(world: &World) -> Self {
+        use crate::my_strategy::entity::Entity;
+
+        let simulator = make_simulator(world);
+        let mut result = BallPredictor {
+            tick: world.game.current_tick,
+            micro_ticks_per_tick: world.config.near_micro_ticks_per_tick,
+            states: vec![BallState {
+                position: simulator.ball().position(),
+                velocity: simulator.ball().velocity(),
+                collision_type: BallCollisionType::None,
+                score: 0,
+            }],
+        };
+        result.extend(world, simulator);
+        result
+    }
+
+    pub fn advance(&self, world: &World) -> Option<Self> {
+        use crate::my_strategy::entity::Entity;
+
+        let offset = world.game.current_tick - self.tick;
+
+        if offset < 0 || self.micro_ticks_per_tick != world.config.near_micro_ticks_per_tick {
+            return None;
+        }
+
+        let state = self.get(offset)?;
+        let ball = &world.game.ball;
+
+        if state.position.distance(ball.position()) > world.config.ball_predictor_max_error
+            || state.velocity.distance(ball.velocity()) > world.config.ball_predictor_max_error {
+            return None;
+        }
+
+        let mut states = self.states[offset as usize..].to_vec();
+        states[0].position = ball.position();
+        states[0].velocity = ball.velocity();
+
+        let mut simulator = make_simulator(world);
+        let last = *states.last().unwrap();
+        simulator.ball_mut().set_position(last.position);
+        simulator.ball_mut().set_velocity(last.velocity);
+
+        let mut result = BallPredictor {
+            tick: world.game.current_tick,
+            micro_ticks_per_tick: self.micro_ticks_per_tick,
+            states,
+        };
+        result.extend(world, simulator);
+        Some(result)
+    }
+
+    pub fn opposite(&self) -> Self {
+        BallPredictor {
+            tick: self.tick,
+            micro_ticks_per_tick: self.micro_ticks_per_tick,
+            states: self.states.iter()
+                .map(|v| BallState {
+                    position: v.position.opposite(),
+                    velocity: v.velocity.opposite(),
+                    collision_type: v.collision_type,
+                    score: -v.score,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn tick(&self) -> i32 {
+        self.tick
+    }
+
+    pub fn micro_ticks_per_tick(&self) -> usize {
+        self.micro_ticks_per_tick
+    }
+
+    pub fn get(&self, tick: i32) -> Option<&BallState> {
+        if tick < 0 {
+            return None;
+        }
+        self.states.get(tick as usize)
+    }
+
+    pub fn is_on_trajectory(&self, tick: i32, position: Vec3, velocity: Vec3) -> bool {
+        self.get(tick)
+            .map(|v| v.position == position && v.velocity == velocity)
+            .unwrap_or(false)
+    }
+
+    fn extend(&mut self, world: &World, mut simulator: Simulator) {
+        use crate::my_strategy::random::{XorShiftRng, SeedableRng};
+        use crate::my_strategy::entity::Entity;
+
+        let mut rng = XorShiftRng::from_seed([2960285387, 943075939, 3311701793, 474463886]);
+        let score = self.states.last().unwrap().score;
+
+        while self.states.len() <= world.config.max_ticks as usize {
+            simulator.tick(world.rules.tick_time_interval(), self.micro_ticks_per_tick, &mut rng);
+            self.states.push(BallState {
+                position: simulator.ball().position(),
+                velocity: simulator.ball().velocity(),
+                collision_type: simulator.ball().collision_type(),
+                score: if score != 0 { score } else { simulator.score() },
+            });
+        }
+    }
+}
+
+fn make_simulator(world: &World) -> Simulator {
+    let mut simulator = Simulator::new(world, world.me.id);
+
+    simulator.robots_mut().iter_mut()
+        .for_each(|v| v.set_ignore(true));
+
+    simulator
+}
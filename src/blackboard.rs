@@ -0,0 +1,137 @@
+use crate::my_strategy::world::World;
+use crate::my_strategy::orders::Order;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Intention {
+    Idle,
+    Play,
+    WalkToGoalkeeperPosition,
+    WalkToPosition,
+    TakeNitroPack,
+    PushOpponent,
+    BlockLane,
+    Mark,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BallTouch {
+    pub robot_id: i32,
+    pub is_teammate: bool,
+    pub time: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpponentPlan {
+    pub robot_id: i32,
+    pub time_to_ball: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Blackboard {
+    intentions: Vec<(i32, Intention)>,
+    ball_touches: Vec<BallTouch>,
+    nitro_packs: Vec<(i32, i32)>,
+    nitro_pack_assignment: Vec<(i32, i32)>,
+    marks: Vec<(i32, i32)>,
+    previous_marks: Vec<(i32, i32)>,
+    opponent_plans: Vec<OpponentPlan>,
+}
+
+impl Blackboard {
+    pub fn new() -> Self {
+        Blackboard::default()
+    }
+
+    pub fn add_order(&mut self, order: &Order, world: &World) {
+        let robot_id = order.robot_id();
+        let is_teammate = world.get_robot(robot_id).is_teammate;
+
+        if !is_teammate {
+            self.set_opponent_plan(robot_id, order.time_to_ball());
+        } else {
+            self.intentions.push((robot_id, get_intention(order)));
+        }
+
+        if let Some(time) = order.time_to_ball() {
+            self.claim_ball_touch(robot_id, is_teammate, time);
+        }
+
+        match order {
+            Order::TakeNitroPack(v) => self.claim_nitro_pack(robot_id, v.nitro_pack_id),
+            Order::Mark(v) => self.claim_mark(robot_id, v.opponent_id),
+            _ => (),
+        }
+    }
+
+    pub fn get_intention(&self, robot_id: i32) -> Option<Intention> {
+        self.intentions.iter()
+            .find(|(id, _)| *id == robot_id)
+            .map(|(_, intention)| *intention)
+    }
+
+    pub fn claim_ball_touch(&mut self, robot_id: i32, is_teammate: bool, time: f64) {
+        self.ball_touches.push(BallTouch { robot_id, is_teammate, time });
+    }
+
+    pub fn is_ball_claimed_by_teammate(&self) -> bool {
+        self.ball_touches.iter().any(|v| v.is_teammate)
+    }
+
+    pub fn claim_nitro_pack(&mut self, robot_id: i32, nitro_pack_id: i32) {
+        self.nitro_packs.push((robot_id, nitro_pack_id));
+    }
+
+    pub fn is_nitro_pack_claimed(&self, nitro_pack_id: i32, robot_id: i32) -> bool {
+        self.nitro_packs.iter().any(|(id, pack_id)| *pack_id == nitro_pack_id && *id != robot_id)
+    }
+
+    pub fn set_nitro_pack_assignment(&mut self, assignment: Vec<(i32, i32)>) {
+        self.nitro_pack_assignment = assignment;
+    }
+
+    pub fn get_assigned_nitro_pack_id(&self, robot_id: i32) -> Option<i32> {
+        self.nitro_pack_assignment.iter()
+            .find(|(id, _)| *id == robot_id)
+            .map(|(_, nitro_pack_id)| *nitro_pack_id)
+    }
+
+    pub fn claim_mark(&mut self, robot_id: i32, opponent_id: i32) {
+        self.marks.push((robot_id, opponent_id));
+    }
+
+    pub fn is_marked(&self, opponent_id: i32, robot_id: i32) -> bool {
+        self.marks.iter().any(|(id, marked_id)| *marked_id == opponent_id && *id != robot_id)
+    }
+
+    pub fn set_previous_marks(&mut self, marks: Vec<(i32, i32)>) {
+        self.previous_marks = marks;
+    }
+
+    pub fn get_previous_mark(&self, robot_id: i32) -> Option<i32> {
+        self.previous_marks.iter()
+            .find(|(id, _)| *id == robot_id)
+            .map(|(_, opponent_id)| *opponent_id)
+    }
+
+    pub fn set_opponent_plan(&mut self, robot_id: i32, time_to_ball: Option<f64>) {
+        self.opponent_plans.retain(|v| v.robot_id != robot_id);
+        self.opponent_plans.push(OpponentPlan { robot_id, time_to_ball });
+    }
+
+    pub fn get_opponent_plan(&self, robot_id: i32) -> Option<&OpponentPlan> {
+        self.opponent_plans.iter().find(|v| v.robot_id == robot_id)
+    }
+}
+
+pub fn get_intention(order: &Order) -> Intention {
+    match order {
+        Order::Idle(_) => Intention::Idle,
+        Order::Play(_) => Intention::Play,
+        Order::WalkToGoalkeeperPosition(_) => Intention::WalkToGoalkeeperPosition,
+        Order::WalkToPosition(_) => Intention::WalkToPosition,
+        Order::TakeNitroPack(_) => Intention::TakeNitroPack,
+        Order::PushOpponent(_) => Intention::PushOpponent,
+        Order::BlockLane(_) => Intention::BlockLane,
+        Order::Mark(_) => Intention::Mark,
+    }
+}
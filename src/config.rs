@@ -1,3 +1,17 @@
+use std::rc::Rc;
+use crate::my_strategy::learning::{Model, deserialize_evaluation_model};
+use crate::my_strategy::opponent_model::OpponentModel;
+use crate::my_strategy::kickoff::KickoffPlan;
+use crate::my_strategy::reachability::{ReachabilityTable, deserialize_reachability_table};
+use crate::my_strategy::roles::Formation;
+use crate::my_strategy::fallback::FallbackPolicy;
+
+#[derive(Debug, Clone, Deserialize)]
+pub enum EvaluatorConfig {
+    Linear,
+    Learned(#[serde(deserialize_with = "deserialize_evaluation_model")] Model),
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct Config {
     pub max_ticks: i32,
@@ -18,6 +32,53 @@ pub struct Config {
     pub opponent_time_to_ball_penalty_weight: f64,
     pub nitro_amount_score_weight: f64,
     pub goalkeeper_max_z_factor: f64,
+    pub forward_max_z: Option<f64>,
+    pub max_jump_refinement_iterations: usize,
+    pub use_joint_planning: bool,
+    pub joint_planning_max_distance: f64,
+    pub use_aerial_control: bool,
+    pub aerial_nitro_budget_fractions: Vec<f64>,
+    pub use_wall_play: bool,
+    pub wall_play_max_normal_y: f64,
+    pub use_passes: bool,
+    pub pass_prediction_time: f64,
+    pub pass_teammate_time_to_ball_score_weight: f64,
+    pub pass_follow_up_shot_score_weight: f64,
+    pub evaluator: EvaluatorConfig,
+    pub threat_penalty_weight: f64,
+    pub threat_max_iterations: usize,
+    pub threat_max_plan_micro_ticks: usize,
+    pub opponent_model: Option<OpponentModel>,
+    pub opponent_profile_aggression_penalty_factor: f64,
+    pub max_predicted_opponents: usize,
+    pub opponent_prediction_max_distance: f64,
+    pub opponent_prediction_max_iterations: usize,
+    pub opponent_prediction_max_plan_micro_ticks: usize,
+    pub kickoff_playbook: Vec<KickoffPlan>,
+    pub kickoff_max_mismatch: f64,
+    pub use_ball_predictor: bool,
+    pub ball_predictor_max_error: f64,
+    #[serde(deserialize_with = "deserialize_reachability_table")]
+    pub reachability_table: Option<Rc<ReachabilityTable>>,
+    pub formation: Formation,
+    pub use_dynamic_goalkeeper_zone: bool,
+    pub goalkeeper_zone_max_ball_speed: f64,
+    pub goalkeeper_zone_prediction_time: f64,
+    pub goalkeeper_zone_extension_radius: f64,
+    pub use_goalkeeper_coverage_position: bool,
+    pub goalkeeper_coverage_shot_speed: f64,
+    pub goalkeeper_coverage_prediction_time: f64,
+    pub use_order_commitment: bool,
+    pub order_commitment_margin: i32,
+    pub use_block_lane: bool,
+    pub block_lane_max_opponent_distance: f64,
+    pub use_mark: bool,
+    pub mark_distance: f64,
+    pub mark_prediction_time: f64,
+    pub use_nitro_economy: bool,
+    pub nitro_detour_max_time: f64,
+    pub nitro_pack_max_wait_time: f64,
+    pub fallback_policy: FallbackPolicy,
 }
 
 impl Config {
@@ -45,6 +106,52 @@ impl Config {
             opponent_time_to_ball_penalty_weight: 0.09999078428632137,
             nitro_amount_score_weight: 0.10000021192195667,
             goalkeeper_max_z_factor: 1.6666666666666667,
+            forward_max_z: None,
+            max_jump_refinement_iterations: 10,
+            use_joint_planning: false,
+            joint_planning_max_distance: 15.0,
+            use_aerial_control: false,
+            aerial_nitro_budget_fractions: vec![0.5, 1.0],
+            use_wall_play: false,
+            wall_play_max_normal_y: 0.7,
+            use_passes: false,
+            pass_prediction_time: 0.5,
+            pass_teammate_time_to_ball_score_weight: 0.2,
+            pass_follow_up_shot_score_weight: 0.3,
+            evaluator: EvaluatorConfig::Linear,
+            threat_penalty_weight: 0.0,
+            threat_max_iterations: 20,
+            threat_max_plan_micro_ticks: 2000,
+            opponent_model: None,
+            opponent_profile_aggression_penalty_factor: 0.0,
+            max_predicted_opponents: 1,
+            opponent_prediction_max_distance: 10.0,
+            opponent_prediction_max_iterations: 30,
+            opponent_prediction_max_plan_micro_ticks: 6000,
+            kickoff_playbook: Vec::new(),
+            kickoff_max_mismatch: 5.0,
+            use_ball_predictor: false,
+            ball_predictor_max_error: 0.001,
+            reachability_table: None,
+            formation: Formation::Classic,
+            use_dynamic_goalkeeper_zone: false,
+            goalkeeper_zone_max_ball_speed: 10.0,
+            goalkeeper_zone_prediction_time: 0.5,
+            goalkeeper_zone_extension_radius: 8.0,
+            use_goalkeeper_coverage_position: false,
+            goalkeeper_coverage_shot_speed: 40.0,
+            goalkeeper_coverage_prediction_time: 0.1,
+            use_order_commitment: false,
+            order_commitment_margin: 10,
+            use_block_lane: false,
+            block_lane_max_opponent_distance: 10.0,
+            use_mark: false,
+            mark_distance: 3.0,
+            mark_prediction_time: 0.5,
+            use_nitro_economy: false,
+            nitro_detour_max_time: 3.0,
+            nitro_pack_max_wait_time: 1.0,
+            fallback_policy: FallbackPolicy::new(),
         }
     }
 }
@@ -0,0 +1,134 @@
+use std::rc::Rc;
+use crate::my_strategy::simulator::Simulator;
+use crate::my_strategy::vec3::Vec3;
+use crate::my_strategy::config::{Config, EvaluatorConfig};
+use crate::my_strategy::learning::Model;
+
+pub struct Outcome<'r> {
+    pub simulator: &'r Simulator,
+    pub target: Vec3,
+    pub max_time: f64,
+    pub my_time_to_ball: Option<f64>,
+    pub opponent_time_to_ball: Option<f64>,
+    pub time_to_goal: Option<f64>,
+}
+
+pub trait Evaluator {
+    fn evaluate(&self, outcome: &Outcome) -> f64;
+}
+
+pub fn make_evaluator(config: &Config) -> Rc<dyn Evaluator> {
+    match &config.evaluator {
+        EvaluatorConfig::Linear => Rc::new(LinearEvaluator::new(config)),
+        EvaluatorConfig::Learned(model) => Rc::new(LearnedEvaluator::new(model.clone())),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LinearEvaluator {
+    pub ball_goal_distance_score_weight: f64,
+    pub ball_goal_direction_score_weight: f64,
+    pub my_time_to_ball_score_weight: f64,
+    pub time_to_goal_score_weight: f64,
+    pub opponent_time_to_ball_penalty_weight: f64,
+    pub nitro_amount_score_weight: f64,
+}
+
+impl LinearEvaluator {
+    pub fn new(config: &Config) -> Self {
+        LinearEvaluator {
+            ball_goal_distance_score_weight: config.ball_goal_distance_score_weight,
+            ball_goal_direction_score_weight: config.ball_goal_direction_score_weight,
+            my_time_to_ball_score_weight: config.my_time_to_ball_score_weight,
+            time_to_goal_score_weight: config.time_to_goal_score_weight,
+            opponent_time_to_ball_penalty_weight: config.opponent_time_to_ball_penalty_weight,
+            nitro_amount_score_weight: config.nitro_amount_score_weight,
+        }
+    }
+}
+
+impl Evaluator for LinearEvaluator {
+    fn evaluate(&self, outcome: &Outcome) -> f64 {
+        use crate::my_strategy::entity::Entity;
+
+        let simulator = outcome.simulator;
+        let max_time = outcome.max_time;
+        let rules = simulator.rules();
+        let ball = simulator.ball();
+        let me = simulator.me();
+        let to_goal = outcome.target - ball.position();
+
+        let ball_goal_distance_score = if simulator.score() == 0 {
+            1.0 - to_goal.norm() / rules.arena.max_distance()
+        } else if simulator.score() > 0 {
+            2.0
+        } else {
+            -1.0
+        };
+
+        let ball_goal_direction_score = if ball.velocity().norm() > 0.0 {
+            (to_goal.cos(ball.velocity()) + 1.0) / 2.0
+        } else {
+            0.0
+        };
+
+        let my_time_to_ball_score = if let Some(v) = outcome.my_time_to_ball {
+            1.0 - v / max_time
+        } else {
+            0.0
+        };
+
+        let time_to_goal_score = if let Some(v) = outcome.time_to_goal {
+            if simulator.score() > 0 {
+                1.0 - v / max_time
+            } else {
+                v / max_time
+            }
+        } else {
+            0.0
+        };
+
+        let opponent_time_to_ball_penalty = if let Some(v) = outcome.opponent_time_to_ball {
+            1.0 - v / max_time
+        } else {
+            0.0
+        };
+
+        let nitro_amount_score = me.nitro_amount() / rules.MAX_NITRO_AMOUNT;
+
+        0.0
+            + ball_goal_distance_score * self.ball_goal_distance_score_weight
+            + ball_goal_direction_score * self.ball_goal_direction_score_weight
+            + my_time_to_ball_score * self.my_time_to_ball_score_weight
+            + time_to_goal_score * self.time_to_goal_score_weight
+            - opponent_time_to_ball_penalty * self.opponent_time_to_ball_penalty_weight
+            + nitro_amount_score * self.nitro_amount_score_weight
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LearnedEvaluator {
+    pub model: Model,
+}
+
+impl LearnedEvaluator {
+    pub fn new(model: Model) -> Self {
+        LearnedEvaluator { model }
+    }
+}
+
+impl Evaluator for LearnedEvaluator {
+    fn evaluate(&self, outcome: &Outcome) -> f64 {
+        use crate::my_strategy::learning::get_features;
+
+        let simulator = outcome.simulator;
+
+        if simulator.score() > 0 {
+            1.0
+        } else if simulator.score() < 0 {
+            0.0
+        } else {
+            self.model.predict(&get_features(simulator))
+        }
+    }
+}
@@ -3,7 +3,7 @@ use crate::my_strategy::world::World;
 use crate::my_strategy::random::{XorShiftRng, SeedableRng};
 use crate::my_strategy::config::Config;
 
-#[derive(Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub enum GameType {
     TwoRobots,
     TwoRobotsWithNitro,
@@ -0,0 +1,176 @@
+use crate::model::Robot;
+use crate::my_strategy::world::World;
+use crate::my_strategy::config::Config;
+use crate::my_strategy::orders::Order;
+use crate::my_strategy::roles::Role;
+use crate::my_strategy::blackboard::Blackboard;
+use crate::my_strategy::common::IdGenerator;
+use crate::my_strategy::zone::Zone;
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub enum FallbackOrder {
+    TakeNitroPack,
+    PushOpponent,
+    BlockLane,
+    Mark,
+    WalkToGoalkeeperPosition,
+    WalkToDefenderPosition,
+    WalkToMidfielderPosition,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub enum FallbackGuard {
+    UseBlockLane,
+    UseMark,
+    NitroBelowMax,
+    NitroBelowStart,
+    NitroFull,
+    BallClaimedByTeammate,
+    MinTeamSize(i32),
+    BallFarFromGoalkeeperPosition(f64),
+    OpponentAggressionAbove(f64),
+    OpponentJumpFrequencyAbove(f64),
+    OpponentNitroUsageAbove(f64),
+    OpponentGoalkeeperRateAbove(f64),
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct FallbackRule {
+    pub order: FallbackOrder,
+    pub guards: Vec<FallbackGuard>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct FallbackPolicy {
+    pub goalkeeper: Vec<FallbackRule>,
+    pub defender: Vec<FallbackRule>,
+    pub midfielder: Vec<FallbackRule>,
+    pub forward: Vec<FallbackRule>,
+}
+
+impl FallbackRule {
+    pub fn new(order: FallbackOrder, guards: Vec<FallbackGuard>) -> Self {
+        FallbackRule { order, guards }
+    }
+
+    pub fn is_applicable(&self, robot: &Robot, world: &World, config: &Config, blackboard: &Blackboard) -> bool {
+        self.guards.iter().all(|v| v.check(robot, world, config, blackboard))
+    }
+}
+
+impl FallbackPolicy {
+    pub fn new() -> Self {
+        FallbackPolicy {
+            goalkeeper: vec![
+                FallbackRule::new(
+                    FallbackOrder::TakeNitroPack,
+                    vec![FallbackGuard::NitroBelowStart, FallbackGuard::BallFarFromGoalkeeperPosition(6.0)],
+                ),
+                FallbackRule::new(FallbackOrder::WalkToGoalkeeperPosition, vec![]),
+            ],
+            defender: vec![
+                FallbackRule::new(FallbackOrder::WalkToDefenderPosition, vec![]),
+            ],
+            midfielder: vec![
+                FallbackRule::new(FallbackOrder::TakeNitroPack, vec![FallbackGuard::NitroBelowMax]),
+                FallbackRule::new(FallbackOrder::WalkToMidfielderPosition, vec![]),
+            ],
+            forward: vec![
+                FallbackRule::new(
+                    FallbackOrder::BlockLane,
+                    vec![FallbackGuard::UseBlockLane, FallbackGuard::BallClaimedByTeammate],
+                ),
+                FallbackRule::new(FallbackOrder::TakeNitroPack, vec![FallbackGuard::NitroBelowMax]),
+                FallbackRule::new(
+                    FallbackOrder::Mark,
+                    vec![FallbackGuard::UseMark, FallbackGuard::MinTeamSize(3), FallbackGuard::NitroFull],
+                ),
+                FallbackRule::new(FallbackOrder::PushOpponent, vec![FallbackGuard::NitroFull]),
+            ],
+        }
+    }
+
+    pub fn get_rules(&self, role: &Role) -> &[FallbackRule] {
+        match role {
+            Role::Goalkeeper(_) => &self.goalkeeper,
+            Role::Defender(_) => &self.defender,
+            Role::Midfielder(_) => &self.midfielder,
+            Role::Forward(_) => &self.forward,
+        }
+    }
+
+    pub fn give_order(&self, role: &Role, robot: &Robot, world: &World, config: &Config, blackboard: &Blackboard,
+                      order_id_generator: &mut IdGenerator) -> Order {
+        let zone = role.zone(world);
+
+        for rule in self.get_rules(role).iter() {
+            if !rule.is_applicable(robot, world, config, blackboard) {
+                continue;
+            }
+            let order = rule.order.try_give(robot, world, blackboard, zone, order_id_generator);
+            if !order.is_idle() {
+                log!(world.game.current_tick, "[{}] <{}> fallback to {:?}", robot.id, order.id(), rule.order);
+                return order;
+            }
+        }
+        Order::idle(robot, world, order_id_generator)
+    }
+}
+
+impl Default for FallbackPolicy {
+    fn default() -> Self {
+        FallbackPolicy::new()
+    }
+}
+
+impl FallbackOrder {
+    pub fn try_give(&self, robot: &Robot, world: &World, blackboard: &Blackboard, zone: Zone,
+                    order_id_generator: &mut IdGenerator) -> Order {
+        match self {
+            FallbackOrder::TakeNitroPack => Order::try_take_nitro_pack(robot, world, blackboard, zone, order_id_generator),
+            FallbackOrder::PushOpponent => Order::try_push_opponent(robot, world, zone, order_id_generator),
+            FallbackOrder::BlockLane => Order::try_block_lane(robot, world, blackboard, zone, order_id_generator),
+            FallbackOrder::Mark => Order::try_mark(robot, world, blackboard, zone, order_id_generator),
+            FallbackOrder::WalkToGoalkeeperPosition => Order::walk_to_goalkeeper_position(robot, world, order_id_generator),
+            FallbackOrder::WalkToDefenderPosition => {
+                let target = world.rules.get_defender_position(world.game.ball.position());
+                Order::walk_to_position(robot, world, target, "walk_to_defender_position", order_id_generator)
+            },
+            FallbackOrder::WalkToMidfielderPosition => {
+                let target = world.rules.get_midfielder_position(world.game.ball.position());
+                Order::walk_to_position(robot, world, target, "walk_to_midfielder_position", order_id_generator)
+            },
+        }
+    }
+}
+
+impl FallbackGuard {
+    pub fn check(&self, robot: &Robot, world: &World, config: &Config, blackboard: &Blackboard) -> bool {
+        match self {
+            FallbackGuard::UseBlockLane => config.use_block_lane,
+            FallbackGuard::UseMark => config.use_mark,
+            FallbackGuard::NitroBelowMax => robot.nitro_amount < world.rules.MAX_NITRO_AMOUNT,
+            FallbackGuard::NitroBelowStart => robot.nitro_amount < world.rules.START_NITRO_AMOUNT,
+            FallbackGuard::NitroFull => robot.nitro_amount >= world.rules.MAX_NITRO_AMOUNT,
+            FallbackGuard::BallClaimedByTeammate => blackboard.is_ball_claimed_by_teammate(),
+            FallbackGuard::MinTeamSize(v) => world.rules.team_size >= *v,
+            FallbackGuard::BallFarFromGoalkeeperPosition(margin) => {
+                let ball_position = world.game.ball.position();
+                ball_position.distance(world.rules.get_goalkeeper_position(ball_position))
+                    > world.rules.arena.depth / 2.0 + margin
+            },
+            FallbackGuard::OpponentAggressionAbove(v) => world.opponent_profile.as_ref()
+                .map(|profile| profile.aggression(&world.rules) > *v)
+                .unwrap_or(false),
+            FallbackGuard::OpponentJumpFrequencyAbove(v) => world.opponent_profile.as_ref()
+                .map(|profile| profile.jump_frequency(&world.rules) > *v)
+                .unwrap_or(false),
+            FallbackGuard::OpponentNitroUsageAbove(v) => world.opponent_profile.as_ref()
+                .map(|profile| profile.nitro_usage(&world.rules) > *v)
+                .unwrap_or(false),
+            FallbackGuard::OpponentGoalkeeperRateAbove(v) => world.opponent_profile.as_ref()
+                .map(|profile| profile.goalkeeper_rate() > *v)
+                .unwrap_or(false),
+        }
+    }
+}
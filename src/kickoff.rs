@@ -0,0 +1,108 @@
+use crate::model::Action;
+use crate::my_strategy::vec3::Vec3;
+use crate::my_strategy::world::World;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KickoffPlan {
+    pub team_size: i32,
+    pub nitro: bool,
+    pub opponents: Vec<Vec3>,
+    pub robots: Vec<KickoffRobotPlan>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KickoffRobotPlan {
+    pub position: Vec3,
+    pub actions: Vec<Action>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Kickoff {
+    pub start_tick: i32,
+    pub plan: KickoffPlan,
+    pub robots_ids: Vec<i32>,
+}
+
+impl KickoffPlan {
+    pub fn is_suitable(&self, world: &World) -> bool {
+        self.team_size == world.rules.team_size
+            && self.nitro != world.game.nitro_packs.is_empty()
+            && self.robots.len() == world.game.robots.iter().filter(|v| v.is_teammate).count()
+    }
+
+    pub fn get_mismatch(&self, world: &World) -> f64 {
+        let opponents: Vec<Vec3> = world.game.robots.iter()
+            .filter(|v| !v.is_teammate)
+            .map(|v| v.position())
+            .collect();
+
+        let robots_mismatch = self.assign_robots(world).iter()
+            .zip(self.robots.iter())
+            .map(|(id, v)| world.get_robot(*id).position().distance(v.position))
+            .sum::<f64>();
+
+        let opponents_mismatch = self.opponents.iter()
+            .map(|position| {
+                opponents.iter()
+                    .map(|v| v.distance(*position))
+                    .min_by(|a, b| a.partial_cmp(b).unwrap())
+                    .unwrap_or_default()
+            })
+            .sum::<f64>();
+
+        robots_mismatch + opponents_mismatch
+    }
+
+    pub fn assign_robots(&self, world: &World) -> Vec<i32> {
+        use crate::my_strategy::common::as_score;
+
+        let mut robots: Vec<_> = world.game.robots.iter()
+            .filter(|v| v.is_teammate)
+            .collect();
+
+        self.robots.iter()
+            .filter_map(|plan| {
+                robots.iter()
+                    .enumerate()
+                    .min_by_key(|(_, v)| as_score(v.position().distance(plan.position)))
+                    .map(|(n, _)| n)
+                    .map(|n| robots.remove(n).id)
+            })
+            .collect()
+    }
+}
+
+impl Kickoff {
+    pub fn try_new(world: &World) -> Option<Self> {
+        use crate::my_strategy::common::as_score;
+
+        world.config.kickoff_playbook.iter()
+            .filter(|v| v.is_suitable(world))
+            .map(|v| (v.get_mismatch(world), v))
+            .filter(|(mismatch, _)| *mismatch <= world.config.kickoff_max_mismatch)
+            .min_by_key(|(mismatch, _)| as_score(*mismatch))
+            .map(|(mismatch, plan)| {
+                log!(world.game.current_tick, "use kickoff plan with mismatch {}", mismatch);
+                Kickoff {
+                    start_tick: world.game.current_tick,
+                    robots_ids: plan.assign_robots(world),
+                    plan: plan.clone(),
+                }
+            })
+    }
+
+    pub fn get_actions(&self, robot_id: i32, current_tick: i32) -> Option<&[Action]> {
+        let tick = (current_tick - self.start_tick) as usize;
+
+        self.robots_ids.iter()
+            .position(|v| *v == robot_id)
+            .map(|n| &self.plan.robots[n].actions)
+            .filter(|v| tick < v.len())
+            .map(|v| &v[tick..])
+    }
+
+    pub fn is_finished(&self, world: &World) -> bool {
+        world.game.ball.velocity().with_y(0.0).norm() > 0.0
+            || self.robots_ids.iter().all(|v| self.get_actions(*v, world.game.current_tick).is_none())
+    }
+}
@@ -0,0 +1,153 @@
+use crate::my_strategy::simulator::Simulator;
+
+pub const FEATURES_NUMBER: usize = 15;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Activation {
+    Identity,
+    Relu,
+    Sigmoid,
+}
+
+impl Activation {
+    pub fn apply(&self, value: f64) -> f64 {
+        match self {
+            Activation::Identity => value,
+            Activation::Relu => value.max(0.0),
+            Activation::Sigmoid => 1.0 / (1.0 + (-value).exp()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Layer {
+    pub weights: Vec<Vec<f64>>,
+    pub biases: Vec<f64>,
+    pub activation: Activation,
+}
+
+impl Layer {
+    pub fn apply(&self, input: &[f64]) -> Vec<f64> {
+        self.weights.iter()
+            .zip(self.biases.iter())
+            .map(|(weights, bias)| {
+                let sum = weights.iter().zip(input.iter()).map(|(w, x)| w * x).sum::<f64>();
+                self.activation.apply(sum + bias)
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Model {
+    pub layers: Vec<Layer>,
+}
+
+#[derive(Deserialize)]
+struct ModelData {
+    layers: Vec<Layer>,
+}
+
+impl<'de> serde::Deserialize<'de> for Model {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: serde::Deserializer<'de> {
+
+        let data = ModelData::deserialize(deserializer)?;
+        let result = Model { layers: data.layers };
+        result.validate().map_err(serde::de::Error::custom)?;
+        Ok(result)
+    }
+}
+
+impl Model {
+    pub fn predict(&self, features: &[f64]) -> f64 {
+        self.layers.iter()
+            .fold(features.to_vec(), |input, layer| layer.apply(&input))[0]
+    }
+
+    pub fn features_number(&self) -> usize {
+        self.layers.first()
+            .and_then(|v| v.weights.first())
+            .map(|v| v.len())
+            .unwrap_or(0)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.layers.is_empty() {
+            return Err(String::from("layers should not be empty"));
+        }
+
+        let mut inputs = self.features_number();
+
+        for (i, layer) in self.layers.iter().enumerate() {
+            if layer.weights.is_empty() {
+                return Err(format!("layer {} should have at least 1 output", i));
+            }
+            if layer.biases.len() != layer.weights.len() {
+                return Err(format!("layer {} should have {} biases but has {}", i, layer.weights.len(), layer.biases.len()));
+            }
+            if let Some(weights) = layer.weights.iter().find(|v| v.len() != inputs) {
+                return Err(format!("layer {} should have {} weights per output but has {}", i, inputs, weights.len()));
+            }
+            inputs = layer.weights.len();
+        }
+
+        if inputs != 1 {
+            return Err(format!("last layer should have 1 output but has {}", inputs));
+        }
+
+        Ok(())
+    }
+
+    pub fn validate_features_number(&self, features_number: usize) -> Result<(), String> {
+        if self.features_number() != features_number {
+            return Err(format!("model should have {} features but has {}", features_number, self.features_number()));
+        }
+        Ok(())
+    }
+}
+
+pub fn deserialize_evaluation_model<'de, D>(deserializer: D) -> Result<Model, D::Error>
+    where D: serde::Deserializer<'de> {
+    use serde::Deserialize;
+
+    let result = Model::deserialize(deserializer)?;
+    result.validate_features_number(FEATURES_NUMBER).map_err(serde::de::Error::custom)?;
+    Ok(result)
+}
+
+pub fn get_features(simulator: &Simulator) -> Vec<f64> {
+    use crate::my_strategy::entity::Entity;
+
+    let rules = simulator.rules();
+    let arena = &rules.arena;
+    let ball = simulator.ball();
+    let me = simulator.me();
+
+    let min_distance_to_ball = |is_teammate: bool| {
+        simulator.robots().iter()
+            .filter(|v| v.is_teammate() == is_teammate)
+            .map(|v| v.position().distance(ball.position()))
+            .min_by(|a, b| a.partial_cmp(b).unwrap())
+            .map(|v| v / arena.max_distance())
+            .unwrap_or(1.0)
+    };
+
+    vec![
+        ball.position().x() / (arena.width / 2.0),
+        ball.position().y() / arena.height,
+        ball.position().z() / (arena.depth / 2.0),
+        ball.velocity().x() / rules.MAX_ENTITY_SPEED,
+        ball.velocity().y() / rules.MAX_ENTITY_SPEED,
+        ball.velocity().z() / rules.MAX_ENTITY_SPEED,
+        me.position().x() / (arena.width / 2.0),
+        me.position().y() / arena.height,
+        me.position().z() / (arena.depth / 2.0),
+        me.velocity().x() / rules.MAX_ENTITY_SPEED,
+        me.velocity().y() / rules.MAX_ENTITY_SPEED,
+        me.velocity().z() / rules.MAX_ENTITY_SPEED,
+        me.nitro_amount() / rules.MAX_NITRO_AMOUNT,
+        min_distance_to_ball(true),
+        min_distance_to_ball(false),
+    ]
+}
@@ -9,3 +9,4 @@ pub mod model;
 pub mod strategy;
 pub mod my_strategy;
 pub mod examples;
+pub mod training;
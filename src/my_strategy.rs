@@ -25,7 +25,7 @@ pub mod plane;
 pub mod sphere;
 
 #[path = "optimization.rs"]
-mod optimization;
+pub mod optimization;
 
 #[cfg(feature = "enable_render")]
 #[path = "render.rs"]
@@ -71,27 +71,67 @@ pub mod world;
 #[path = "stats.rs"]
 pub mod stats;
 
+#[cfg(feature = "enable_trace")]
+#[path = "trace.rs"]
+pub mod trace;
+
 #[path = "physics.rs"]
 pub mod physics;
 
 #[path = "simulator.rs"]
 pub mod simulator;
 
+#[path = "zone.rs"]
+pub mod zone;
+
+#[path = "ball_predictor.rs"]
+pub mod ball_predictor;
+
+#[path = "reachability.rs"]
+pub mod reachability;
+
 #[path = "scenarios.rs"]
 pub mod scenarios;
 
 #[path = "search.rs"]
 pub mod search;
 
+#[path = "learning.rs"]
+pub mod learning;
+
+#[path = "opponent_model.rs"]
+pub mod opponent_model;
+
+#[path = "opponent_profile.rs"]
+pub mod opponent_profile;
+
+#[path = "kickoff.rs"]
+pub mod kickoff;
+
+#[path = "evaluation.rs"]
+pub mod evaluation;
+
 #[path = "plan.rs"]
 pub mod plan;
 
+#[path = "assignment.rs"]
+pub mod assignment;
+
+#[path = "nitro_economy.rs"]
+pub mod nitro_economy;
+
 #[path = "roles.rs"]
 pub mod roles;
 
 #[path = "orders.rs"]
 pub mod orders;
 
+#[path = "blackboard.rs"]
+pub mod blackboard;
+
+#[path = "fallback.rs"]
+pub mod fallback;
+
 #[cfg(feature = "enable_profiler")]
 #[path = "profiler.rs"]
 pub mod profiler;
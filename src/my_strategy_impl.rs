@@ -7,6 +7,10 @@ use crate::my_strategy::orders::Order;
 use crate::my_strategy::common::IdGenerator;
 use crate::my_strategy::roles::Role;
 use crate::my_strategy::config::Config;
+use crate::my_strategy::opponent_profile::OpponentProfile;
+use crate::my_strategy::kickoff::Kickoff;
+use crate::my_strategy::zone::Zone;
+use crate::my_strategy::blackboard::Blackboard;
 
 #[cfg(feature = "enable_render")]
 use crate::my_strategy::render::Render;
@@ -27,6 +31,9 @@ pub struct MyStrategyImpl {
     orders: Vec<Order>,
     robots_priority: Vec<i32>,
     roles: Vec<Role>,
+    opponent_profile: OpponentProfile,
+    kickoff: Option<Kickoff>,
+    is_kickoff_pending: bool,
     order_id_generator: IdGenerator,
     micro_ticks: usize,
     micro_ticks_before: usize,
@@ -59,13 +66,19 @@ impl Strategy for MyStrategyImpl {
                 self.roles.clear();
                 self.robots_priority.clear();
                 self.orders.clear();
+                self.kickoff = None;
+                self.is_kickoff_pending = true;
             } else {
+                self.update_opponent_profile(game);
+                self.update_kickoff();
                 self.assign_roles();
                 self.set_priority();
                 self.give_orders();
             }
             #[cfg(all(feature = "enable_stats", not(feature = "disable_output")))]
             self.print_stats();
+            #[cfg(all(feature = "enable_trace", not(feature = "disable_output")))]
+            self.print_traces();
             #[cfg(feature = "enable_render")]
             self.render();
         } else {
@@ -100,6 +113,9 @@ impl MyStrategyImpl {
             orders: Vec::new(),
             robots_priority: Vec::new(),
             roles: Vec::new(),
+            opponent_profile: OpponentProfile::new(),
+            kickoff: None,
+            is_kickoff_pending: true,
             order_id_generator: IdGenerator::new(),
             micro_ticks: 0,
             micro_ticks_before: 0,
@@ -116,7 +132,7 @@ impl MyStrategyImpl {
     }
 
     fn update_world(&mut self, me: &Robot, game: &Game) {
-        self.world.update(me, game);
+        self.micro_ticks += self.world.update(me, game);
 
         #[cfg(feature = "enable_profiler")]
         self.profiler.stage("update_world", Instant::now());
@@ -129,38 +145,38 @@ impl MyStrategyImpl {
         self.profiler.stage("update_world_me", Instant::now());
     }
 
+    fn update_opponent_profile(&mut self, game: &Game) {
+        use std::rc::Rc;
+
+        self.opponent_profile.update(game, &self.world.rules);
+        self.world.opponent_profile = Some(Rc::new(self.opponent_profile.clone()));
+        self.config.opponent_time_to_ball_penalty_weight = self.opponent_profile
+            .get_opponent_time_to_ball_penalty_weight(&self.world.config, &self.world.rules);
+    }
+
+    fn update_kickoff(&mut self) {
+        if self.is_kickoff_pending {
+            self.is_kickoff_pending = false;
+            self.kickoff = Kickoff::try_new(&self.world);
+        } else if self.kickoff.as_ref().map(|v| v.is_finished(&self.world)).unwrap_or(false) {
+            log!(self.world.game.current_tick, "finish kickoff");
+            self.kickoff = None;
+        }
+
+        #[cfg(feature = "enable_profiler")]
+        self.profiler.stage("update_kickoff", Instant::now());
+    }
+
     fn assign_roles(&mut self) {
-        let current_score = self.roles.iter()
-            .map(|v| v.get_score(&self.world))
-            .sum::<i32>();
         let new_roles = self.get_roles();
-        let new_score = new_roles.iter()
-            .map(|v| {
-                if v.can_quit(&self.world) {
-                    v.get_score(&self.world)
-                } else {
-                    0
-                }
-            })
-            .sum::<i32>();
 
         let is_same = self.roles.len() == new_roles.len()
             && self.roles.iter().zip(new_roles.iter())
                 .all(|(l, r)| l == r);
 
         if !is_same {
-            if self.roles.is_empty() || new_score > current_score + self.config.robot_role_change_gap {
-                log!(
-                    self.world.game.current_tick, "assign roles {:?} with total score {} ({})",
-                    new_roles, new_score, new_score - current_score
-                );
-                self.roles = new_roles;
-            } else {
-                log!(
-                    self.world.game.current_tick, "reject roles {:?} with total score {} ({})",
-                    new_roles, new_score, new_score - current_score
-                );
-            }
+            log!(self.world.game.current_tick, "assign roles {:?}", new_roles);
+            self.roles = new_roles;
         } else {
             log!(self.world.game.current_tick, "use roles {:?}", self.roles);
         }
@@ -170,7 +186,7 @@ impl MyStrategyImpl {
     }
 
     fn get_roles(&self) -> Vec<Role> {
-        use crate::my_strategy::roles::{Forward, Goalkeeper};
+        use crate::my_strategy::roles::get_optimal_roles;
 
         let mut robots_ids: Vec<i32> = self.world.game.robots.iter()
             .filter(|v| v.is_teammate)
@@ -179,44 +195,11 @@ impl MyStrategyImpl {
 
         robots_ids.sort();
 
-        if robots_ids.is_empty() {
-            Vec::new()
-        } else if robots_ids.len() == 1 {
-            if cfg!(feature = "use_single_goalkeeper") {
-                vec![Role::goalkeeper(robots_ids[0])]
-            } else {
-                vec![Role::forward(robots_ids[0])]
-            }
-        } else {
-            let robots = robots_ids.iter()
-                .map(|v| self.world.get_robot(*v))
-                .collect::<Vec<_>>();
+        let mut role_types = self.config.formation.get_role_types(robots_ids.len());
 
-            let forwards = robots.iter()
-                .map(|v| Forward::get_score(*v, &self.world))
-                .collect::<Vec<_>>();
+        role_types.sort();
 
-            let goalkeepers = robots.iter()
-                .map(|v| Goalkeeper::get_score(*v, &self.world))
-                .collect::<Vec<_>>();
-
-            let goalkeeper = goalkeepers.iter()
-                .enumerate()
-                .map(|(n, goalkeeper_score)| {
-                    (*goalkeeper_score + forwards[0..n].iter().chain(forwards[n + 1..forwards.len()].iter()).sum::<i32>(), n)
-                })
-                .max()
-                .map(|(_, n)| n)
-                .unwrap();
-
-            robots_ids[0..goalkeeper].iter().chain(robots_ids[goalkeeper + 1..robots_ids.len()].iter())
-                .map(|v| Role::forward(*v))
-                .chain(
-                    [Role::goalkeeper(robots_ids[goalkeeper])].iter()
-                        .map(|v| v.clone())
-                )
-                .collect()
-        }
+        get_optimal_roles(&robots_ids, &role_types, &self.roles, &self.world, self.config.robot_role_change_gap)
     }
 
     fn set_priority(&mut self) {
@@ -260,73 +243,150 @@ impl MyStrategyImpl {
         use crate::my_strategy::orders::Context;
         use crate::my_strategy::common::as_score;
 
+        let previous_orders = std::mem::take(&mut self.orders);
         let world = &self.world;
-        let mut ctx = Context {
-            config: &self.config,
-            rng: &mut self.rng,
-            order_id_generator: &mut self.order_id_generator,
-            micro_ticks: &mut self.micro_ticks,
-        };
+        let config = &self.config;
         let opposite_world = world.opposite();
         let roles = &self.roles;
+        let kickoff = &self.kickoff;
+        let is_any_robot_planning = world.game.robots.iter()
+            .filter(|v| v.is_teammate)
+            .any(|v| kickoff.as_ref().and_then(|k| k.get_actions(v.id, world.game.current_tick)).is_none());
 
-        let mut other_orders = world.game.robots.iter()
+        let mut opponents = world.game.robots.iter()
             .filter(|v| {
-                !v.is_teammate && v.position().distance(world.game.ball.position()) < 10.0
-            })
-            .min_by_key(|v| {
-                as_score(v.position().distance(world.game.ball.position()))
+                is_any_robot_planning
+                    && !v.is_teammate
+                    && v.position().distance(world.game.ball.position()) < config.opponent_prediction_max_distance
             })
-            .map(|robot| {
-                Order::try_play(&robot.opposite(), &opposite_world, &Vec::new(), std::f64::MAX, &mut ctx).opposite()
-            })
-            .into_iter()
             .collect::<Vec<_>>();
 
+        opponents.sort_by_key(|v| as_score(v.position().distance(world.game.ball.position())));
+        opponents.truncate(config.max_predicted_opponents);
+
+        let mut other_orders = Vec::new();
+        let mut blackboard = Blackboard::new();
+
+        blackboard.set_previous_marks(previous_orders.iter()
+            .filter_map(|v| match v {
+                Order::Mark(v) => Some((v.robot_id, v.opponent_id)),
+                _ => None,
+            })
+            .collect());
+
+        if config.use_nitro_economy {
+            use crate::my_strategy::nitro_economy::assign_nitro_packs;
+
+            blackboard.set_nitro_pack_assignment(assign_nitro_packs(world, |robot| {
+                roles.iter()
+                    .find(|v| v.robot_id() == robot.id)
+                    .map(|v| v.zone(world))
+                    .unwrap_or_else(|| Zone::from(std::f64::MAX))
+            }));
+        }
+
+        if let Some(robot) = opponents.first() {
+            let mut ctx = Context {
+                config,
+                rng: &mut self.rng,
+                order_id_generator: &mut self.order_id_generator,
+                micro_ticks: &mut self.micro_ticks,
+            };
+            let order = Order::try_play(&robot.opposite(), &opposite_world, &Vec::new(), Zone::from(std::f64::MAX), &mut ctx).opposite();
+            blackboard.add_order(&order, world);
+            other_orders.push(order);
+        }
+
+        if opponents.len() > 1 {
+            let prediction_config = get_opponent_prediction_config(config);
+            let mut ctx = Context {
+                config: &prediction_config,
+                rng: &mut self.rng,
+                order_id_generator: &mut self.order_id_generator,
+                micro_ticks: &mut self.micro_ticks,
+            };
+
+            for robot in opponents[1..].iter() {
+                if world.is_micro_ticks_limit_reached(*ctx.micro_ticks) {
+                    break;
+                }
+                log!(world.game.current_tick, "[{}] predict opponent {}", world.me.id, robot.id);
+                let order = Order::try_play(&robot.opposite(), &opposite_world, &Vec::new(), Zone::from(std::f64::MAX), &mut ctx).opposite();
+                blackboard.add_order(&order, world);
+                other_orders.push(order);
+            }
+        }
+
+        let mut ctx = Context {
+            config,
+            rng: &mut self.rng,
+            order_id_generator: &mut self.order_id_generator,
+            micro_ticks: &mut self.micro_ticks,
+        };
+
         for robot_id in self.robots_priority.iter() {
+            if other_orders.iter().any(|v| v.robot_id() == *robot_id) {
+                continue;
+            }
+
             let robot = world.get_robot(*robot_id);
-            let max_z = roles.iter()
+
+            if let Some(actions) = kickoff.as_ref().and_then(|v| v.get_actions(*robot_id, world.game.current_tick)) {
+                let order = Order::follow_kickoff(robot, world, actions, ctx.order_id_generator);
+                blackboard.add_order(&order, world);
+                other_orders.push(order);
+                continue;
+            }
+
+            let zone = roles.iter()
                 .find(|v| v.robot_id() == *robot_id)
                 .unwrap()
-                .max_z(world);
-            let order = Order::try_play(robot, world, &other_orders[..], max_z, &mut ctx);
+                .zone(world);
+            let partner = if config.use_joint_planning {
+                get_joint_planning_partner(robot, roles, &other_orders[..], world, config)
+            } else {
+                None
+            };
+            let (order, partner_order) = if let Some(partner) = partner {
+                log!(world.game.current_tick, "[{}] try joint play with partner {}", robot.id, partner.id);
+                let partner_zone = roles.iter()
+                    .find(|v| v.robot_id() == partner.id)
+                    .unwrap()
+                    .zone(world);
+                Order::try_play_joint(robot, partner, world, &other_orders[..], zone, partner_zone, &mut ctx)
+            } else {
+                (Order::try_play(robot, world, &other_orders[..], zone, &mut ctx), None)
+            };
+
+            let (order, partner_order) = if config.use_order_commitment {
+                let order_id = order.id();
+                let previous = previous_orders.iter().find(|v| v.robot_id() == *robot_id);
+                let order = Order::commit(previous, order, robot, world, &other_orders[..], zone, &mut ctx);
+                if order.id() == order_id {
+                    (order, partner_order)
+                } else {
+                    (order, None)
+                }
+            } else {
+                (order, partner_order)
+            };
 
             let order = if order.is_idle() {
                 let role = roles.iter()
                     .find(|v| v.robot_id() == *robot_id)
                     .unwrap();
 
-                match role {
-                    Role::Forward(_) => {
-                        if robot.nitro_amount < world.rules.MAX_NITRO_AMOUNT {
-                            Order::try_take_nitro_pack(robot, world, max_z, ctx.order_id_generator)
-                        } else {
-                            if cfg!(feature = "use_limited_forward") {
-                                Order::walk_to_goalkeeper_position(robot, world, ctx.order_id_generator)
-                            } else {
-                                Order::try_push_opponent(robot, world, max_z, ctx.order_id_generator)
-                            }
-                        }
-                    },
-                    Role::Goalkeeper(_) => {
-                        if robot.nitro_amount < world.rules.START_NITRO_AMOUNT
-                            && world.game.ball.position().distance(world.rules.get_goalkeeper_position(world.game.ball.position()))
-                                > world.rules.arena.depth / 2.0 + 6.0 {
-
-                            match Order::try_take_nitro_pack(robot, world, max_z, ctx.order_id_generator) {
-                                Order::Idle(_) => Order::walk_to_goalkeeper_position(robot, world, ctx.order_id_generator),
-                                v => v,
-                            }
-                        } else {
-                            Order::walk_to_goalkeeper_position(robot, world, ctx.order_id_generator)
-                        }
-                    },
-                }
+                config.fallback_policy.give_order(role, robot, world, config, &blackboard, ctx.order_id_generator)
             } else {
                 order
             };
 
+            blackboard.add_order(&order, world);
             other_orders.push(order);
+            if let Some(partner_order) = partner_order {
+                blackboard.add_order(&partner_order, world);
+                other_orders.push(partner_order);
+            }
         }
 
         self.orders = other_orders;
@@ -382,6 +442,13 @@ impl MyStrategyImpl {
         }
     }
 
+    #[cfg(feature = "enable_trace")]
+    fn print_traces(&self) {
+        for v in self.orders.iter().filter_map(|v| v.trace()) {
+            println!("{}", serde_json::to_string(v).unwrap());
+        }
+    }
+
     #[cfg(feature = "enable_render")]
     fn render(&mut self) {
         use crate::my_strategy::render::{Object, Color};
@@ -468,3 +535,36 @@ impl MyStrategyImpl {
         self.world.rules.render(render);
     }
 }
+
+fn get_opponent_prediction_config(config: &Config) -> Config {
+    let mut result = config.clone();
+    result.max_iterations = config.opponent_prediction_max_iterations;
+    result.max_plan_micro_ticks = config.opponent_prediction_max_plan_micro_ticks;
+    result.near_micro_ticks_per_tick = config.far_micro_ticks_per_tick;
+    result
+}
+
+fn get_joint_planning_partner<'r>(robot: &Robot, roles: &[Role], other: &[Order], world: &'r World, config: &Config) -> Option<&'r Robot> {
+    use crate::my_strategy::common::as_score;
+
+    let ball_position = world.game.ball.position();
+
+    if robot.position().distance(ball_position) > config.joint_planning_max_distance {
+        return None;
+    }
+
+    let role = roles.iter().find(|v| v.robot_id() == robot.id)?;
+
+    roles.iter()
+        .filter(|v| {
+            match (role, v) {
+                (Role::Forward(_), Role::Goalkeeper(_)) => true,
+                (Role::Goalkeeper(_), Role::Forward(_)) => true,
+                _ => false,
+            }
+        })
+        .filter(|v| !other.iter().any(|order| order.robot_id() == v.robot_id()))
+        .map(|v| world.get_robot(v.robot_id()))
+        .filter(|v| v.position().distance(ball_position) <= config.joint_planning_max_distance)
+        .min_by_key(|v| as_score(v.position().distance(ball_position)))
+}
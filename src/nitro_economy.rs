@@ -0,0 +1,89 @@
+use crate::model::{Robot, NitroPack, Rules};
+use crate::my_strategy::world::World;
+use crate::my_strategy::vec3::Vec3;
+use crate::my_strategy::zone::Zone;
+use crate::my_strategy::assignment::INVALID_SCORE;
+
+pub fn get_available_time(nitro_pack: &NitroPack, rules: &Rules) -> f64 {
+    nitro_pack.respawn_ticks
+        .map(|v| v as f64 * rules.tick_time_interval())
+        .unwrap_or(0.0)
+}
+
+pub fn get_arrival_time(robot: &Robot, target: Vec3, rules: &Rules) -> f64 {
+    let to_target = (target - robot.position()).with_y(0.0);
+    let distance = to_target.norm();
+    if distance == 0.0 {
+        return 0.0;
+    }
+    let speed = robot.velocity().with_y(0.0).dot(to_target / distance);
+    rules.time_for_distance(speed, distance)
+}
+
+pub fn get_take_time(robot: &Robot, nitro_pack: &NitroPack, rules: &Rules) -> f64 {
+    get_arrival_time(robot, nitro_pack.position(), rules)
+        .max(get_available_time(nitro_pack, rules))
+}
+
+pub fn is_taken_by_opponent(robot: &Robot, nitro_pack: &NitroPack, world: &World) -> bool {
+    let take_time = get_take_time(robot, nitro_pack, &world.rules);
+    world.game.robots.iter()
+        .filter(|v| !v.is_teammate && v.nitro_amount < world.rules.MAX_NITRO_AMOUNT)
+        .any(|v| get_take_time(v, nitro_pack, &world.rules) < take_time)
+}
+
+pub fn get_detour_time(robot: &Robot, nitro_pack: &NitroPack, world: &World) -> f64 {
+    let ball_position = world.game.ball.position();
+    let to_ball = (ball_position - nitro_pack.position()).with_y(0.0).norm();
+    get_take_time(robot, nitro_pack, &world.rules)
+        + world.rules.time_for_distance(0.0, to_ball)
+        - get_arrival_time(robot, ball_position, &world.rules)
+}
+
+pub fn get_detour_value(robot: &Robot, nitro_pack: &NitroPack, world: &World) -> f64 {
+    let gain = nitro_pack.nitro_amount.min(world.rules.MAX_NITRO_AMOUNT - robot.nitro_amount);
+    gain / world.rules.MAX_NITRO_AMOUNT * world.config.nitro_detour_max_time
+}
+
+pub fn is_detour_worth(robot: &Robot, nitro_pack: &NitroPack, world: &World) -> bool {
+    get_detour_time(robot, nitro_pack, world) <= get_detour_value(robot, nitro_pack, world)
+}
+
+pub fn assign_nitro_packs<F>(world: &World, get_zone: F) -> Vec<(i32, i32)>
+    where F: Fn(&Robot) -> Zone {
+
+    use crate::my_strategy::common::as_score;
+    use crate::my_strategy::assignment::find_max_assignment;
+
+    let robots: Vec<&Robot> = world.game.robots.iter()
+        .filter(|v| v.is_teammate && v.nitro_amount < world.rules.MAX_NITRO_AMOUNT)
+        .collect();
+    let nitro_packs = &world.game.nitro_packs;
+
+    let scores: Vec<Vec<i32>> = robots.iter()
+        .map(|robot| {
+            let zone = get_zone(robot);
+            nitro_packs.iter()
+                .map(|nitro_pack| {
+                    if !zone.contains(nitro_pack.position())
+                        || get_available_time(nitro_pack, &world.rules) > world.config.nitro_pack_max_wait_time
+                        || is_taken_by_opponent(robot, nitro_pack, world)
+                        || !is_detour_worth(robot, nitro_pack, world) {
+                        return INVALID_SCORE;
+                    }
+                    as_score(get_detour_value(robot, nitro_pack, world) - get_detour_time(robot, nitro_pack, world))
+                })
+                .chain(robots.iter().map(|_| 0))
+                .collect()
+        })
+        .collect();
+
+    find_max_assignment(&scores)
+        .map(|(_, slots)| {
+            robots.iter().zip(slots.iter()).zip(scores.iter())
+                .filter(|((_, slot), row)| **slot < nitro_packs.len() && row[**slot] != INVALID_SCORE)
+                .map(|((robot, slot), _)| (robot.id, nitro_packs[*slot].id))
+                .collect()
+        })
+        .unwrap_or_default()
+}
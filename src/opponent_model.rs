@@ -0,0 +1,67 @@
+use crate::model::{Action, Rules};
+use crate::my_strategy::vec3::Vec3;
+use crate::my_strategy::simulator::Simulator;
+use crate::my_strategy::learning::Model;
+
+pub const FEATURES_NUMBER: usize = 6;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpponentModel {
+    #[serde(deserialize_with = "deserialize_intercept_model")]
+    pub intercept: Model,
+    pub speed_factor: f64,
+    pub jump_distance: f64,
+}
+
+impl OpponentModel {
+    pub fn get_action(&self, robot_id: i32, simulator: &Simulator) -> Action {
+        use crate::my_strategy::entity::Entity;
+
+        let rules = simulator.rules();
+        let ball_position = simulator.ball().position().opposite();
+        let position = simulator.get_robot(robot_id).position().opposite();
+        let distance_to_ball = position.distance(ball_position);
+        let is_nearest = simulator.robots().iter()
+            .filter(|v| !v.is_teammate())
+            .all(|v| v.position().opposite().distance(ball_position) >= distance_to_ball);
+        let intercept = self.intercept.predict(&get_features(rules, ball_position, position, is_nearest)) >= 0.5;
+        let target = if intercept {
+            ball_position
+        } else {
+            rules.get_my_goal_target()
+        };
+        let to_target = (target - position).with_y(0.0);
+        let mut action = Action::default();
+        if to_target.norm() > 0.0 {
+            action.set_target_velocity(
+                to_target.normalized() * rules.ROBOT_MAX_GROUND_SPEED * self.speed_factor
+            );
+        }
+        if intercept && distance_to_ball < self.jump_distance && ball_position.y() > position.y() {
+            action.jump_speed = rules.ROBOT_MAX_JUMP_SPEED;
+        }
+        action.opposite()
+    }
+}
+
+fn deserialize_intercept_model<'de, D>(deserializer: D) -> Result<Model, D::Error>
+    where D: serde::Deserializer<'de> {
+    use serde::Deserialize;
+
+    let result = Model::deserialize(deserializer)?;
+    result.validate_features_number(FEATURES_NUMBER).map_err(serde::de::Error::custom)?;
+    Ok(result)
+}
+
+pub fn get_features(rules: &Rules, ball_position: Vec3, position: Vec3, is_nearest: bool) -> Vec<f64> {
+    let arena = &rules.arena;
+
+    vec![
+        ball_position.x() / (arena.width / 2.0),
+        ball_position.y() / arena.height,
+        ball_position.z() / (arena.depth / 2.0),
+        position.z() / (arena.depth / 2.0),
+        position.distance(ball_position) / arena.max_distance(),
+        if is_nearest { 1.0 } else { 0.0 },
+    ]
+}
@@ -0,0 +1,148 @@
+use crate::model::{Game, Robot, Rules};
+use crate::my_strategy::config::Config;
+
+#[derive(Debug, Clone)]
+pub struct RobotProfile {
+    pub robot_id: i32,
+    pub ticks: usize,
+    pub speed_to_ball_sum: f64,
+    pub jumps: usize,
+    pub nitro_spent: f64,
+    pub goalkeeper_ticks: usize,
+    last: Option<Robot>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct OpponentProfile {
+    pub ticks: usize,
+    pub goalkeeper_ticks: usize,
+    robots: Vec<RobotProfile>,
+    last_tick: Option<i32>,
+}
+
+impl RobotProfile {
+    pub fn new(robot_id: i32) -> Self {
+        RobotProfile {
+            robot_id,
+            ticks: 0,
+            speed_to_ball_sum: 0.0,
+            jumps: 0,
+            nitro_spent: 0.0,
+            goalkeeper_ticks: 0,
+            last: None,
+        }
+    }
+
+    pub fn update(&mut self, robot: &Robot, game: &Game, rules: &Rules, is_next_tick: bool) {
+        let to_ball = game.ball.position() - robot.position();
+        if to_ball.norm() > 0.0 {
+            self.speed_to_ball_sum += robot.velocity().dot(to_ball.normalized());
+        }
+
+        if let Some(last) = self.last.as_ref().filter(|_| is_next_tick) {
+            if last.touch && robot.velocity_y - last.velocity_y > rules.ROBOT_MAX_JUMP_SPEED / 2.0 {
+                self.jumps += 1;
+            }
+            if robot.nitro_amount < last.nitro_amount {
+                self.nitro_spent += last.nitro_amount - robot.nitro_amount;
+            }
+        }
+
+        if is_goalkeeper(robot, rules) {
+            self.goalkeeper_ticks += 1;
+        }
+
+        self.ticks += 1;
+        self.last = Some(robot.clone());
+    }
+
+    pub fn speed_to_ball(&self) -> f64 {
+        ratio(self.speed_to_ball_sum, self.ticks)
+    }
+
+    pub fn aggression(&self, rules: &Rules) -> f64 {
+        get_aggression(self.speed_to_ball(), rules)
+    }
+}
+
+impl OpponentProfile {
+    pub fn new() -> Self {
+        OpponentProfile::default()
+    }
+
+    pub fn update(&mut self, game: &Game, rules: &Rules) {
+        let is_next_tick = self.last_tick == Some(game.current_tick - 1);
+
+        for robot in game.robots.iter().filter(|v| !v.is_teammate) {
+            let index = match self.robots.iter().position(|v| v.robot_id == robot.id) {
+                Some(v) => v,
+                None => {
+                    self.robots.push(RobotProfile::new(robot.id));
+                    self.robots.len() - 1
+                },
+            };
+            self.robots[index].update(robot, game, rules, is_next_tick);
+        }
+
+        if game.robots.iter().any(|v| !v.is_teammate && is_goalkeeper(v, rules)) {
+            self.goalkeeper_ticks += 1;
+        }
+
+        self.ticks += 1;
+        self.last_tick = Some(game.current_tick);
+    }
+
+    pub fn get_robot(&self, robot_id: i32) -> Option<&RobotProfile> {
+        self.robots.iter().find(|v| v.robot_id == robot_id)
+    }
+
+    pub fn speed_to_ball(&self) -> f64 {
+        ratio(
+            self.robots.iter().map(|v| v.speed_to_ball_sum).sum(),
+            self.robots.iter().map(|v| v.ticks).sum(),
+        )
+    }
+
+    pub fn jump_frequency(&self, rules: &Rules) -> f64 {
+        ratio(self.robots.iter().map(|v| v.jumps as f64).sum(), self.ticks) / rules.tick_time_interval()
+    }
+
+    pub fn nitro_usage(&self, rules: &Rules) -> f64 {
+        ratio(self.robots.iter().map(|v| v.nitro_spent).sum(), self.ticks) / rules.tick_time_interval()
+    }
+
+    pub fn goalkeeper_rate(&self) -> f64 {
+        ratio(self.goalkeeper_ticks as f64, self.ticks)
+    }
+
+    pub fn aggression(&self, rules: &Rules) -> f64 {
+        get_aggression(self.speed_to_ball(), rules)
+    }
+
+    pub fn get_opponent_time_to_ball_penalty_weight(&self, config: &Config, rules: &Rules) -> f64 {
+        config.opponent_time_to_ball_penalty_weight
+            * (1.0 + config.opponent_profile_aggression_penalty_factor * self.aggression(rules))
+    }
+
+    pub fn get_threat_factor(&self, robot_id: i32, config: &Config, rules: &Rules) -> f64 {
+        self.get_robot(robot_id)
+            .map(|v| 1.0 + config.opponent_profile_aggression_penalty_factor * v.aggression(rules))
+            .unwrap_or(1.0)
+    }
+}
+
+fn is_goalkeeper(robot: &Robot, rules: &Rules) -> bool {
+    robot.position().with_y(0.0).distance(rules.get_goal_target().with_y(0.0)) < rules.arena.goal_width
+}
+
+fn get_aggression(speed_to_ball: f64, rules: &Rules) -> f64 {
+    (speed_to_ball / rules.ROBOT_MAX_GROUND_SPEED).clamp(0.0, 1.0)
+}
+
+fn ratio(value: f64, count: usize) -> f64 {
+    if count == 0 {
+        0.0
+    } else {
+        value / count as f64
+    }
+}
@@ -34,3 +34,57 @@ pub fn minimize1d<F>(mut begin: f64, mut end: f64, iterations: usize, function:
         });
     (begin + end) / 2.0
 }
+
+pub fn minimize_nelder_mead<F>(initial: &[f64], steps: &[f64], iterations: usize, mut function: F) -> Vec<f64>
+    where F: FnMut(&[f64]) -> f64
+{
+    let dimension = initial.len();
+    let mut simplex: Vec<(f64, Vec<f64>)> = Vec::with_capacity(dimension + 1);
+    simplex.push((function(initial), initial.to_vec()));
+    for i in 0..dimension {
+        let mut point = initial.to_vec();
+        point[i] += steps[i];
+        simplex.push((function(&point), point));
+    }
+    let combine = |a: &[f64], b: &[f64], factor: f64| -> Vec<f64> {
+        a.iter().zip(b.iter())
+            .map(|(a, b)| a + (b - a) * factor)
+            .collect()
+    };
+    for _ in 0..iterations {
+        simplex.sort_by(|(l, _), (r, _)| l.partial_cmp(r).unwrap_or(std::cmp::Ordering::Equal));
+        let centroid = (0..dimension)
+            .map(|i| simplex[0..dimension].iter().map(|(_, v)| v[i]).sum::<f64>() / dimension as f64)
+            .collect::<Vec<_>>();
+        let (worst_value, worst) = simplex[dimension].clone();
+        let reflected = combine(&centroid, &worst, -1.0);
+        let reflected_value = function(&reflected);
+        if reflected_value < simplex[0].0 {
+            let expanded = combine(&centroid, &worst, -2.0);
+            let expanded_value = function(&expanded);
+            simplex[dimension] = if expanded_value < reflected_value {
+                (expanded_value, expanded)
+            } else {
+                (reflected_value, reflected)
+            };
+        } else if reflected_value < simplex[dimension - 1].0 {
+            simplex[dimension] = (reflected_value, reflected);
+        } else {
+            let contracted = combine(&centroid, &worst, 0.5);
+            let contracted_value = function(&contracted);
+            if contracted_value < worst_value {
+                simplex[dimension] = (contracted_value, contracted);
+            } else {
+                let best = simplex[0].1.clone();
+                for vertex in simplex[1..].iter_mut() {
+                    let shrunk = combine(&best, &vertex.1, 0.5);
+                    *vertex = (function(&shrunk), shrunk);
+                }
+            }
+        }
+    }
+    simplex.into_iter()
+        .min_by(|(l, _), (r, _)| l.partial_cmp(r).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(_, v)| v)
+        .unwrap()
+}
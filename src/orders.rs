@@ -4,55 +4,133 @@ use crate::my_strategy::random::XorShiftRng;
 use crate::my_strategy::simulator::Simulator;
 use crate::my_strategy::common::IdGenerator;
 use crate::my_strategy::config::Config;
+use crate::my_strategy::zone::Zone;
+use crate::my_strategy::blackboard::Blackboard;
+use crate::my_strategy::plan::Pass;
+use crate::my_strategy::evaluation::Evaluator;
+use std::rc::Rc;
 
 #[cfg(feature = "enable_render")]
 use crate::my_strategy::render::Render;
 
-#[cfg(feature = "enable_render")]
 use crate::my_strategy::vec3::Vec3;
 
 #[cfg(feature = "enable_stats")]
 use crate::my_strategy::stats::Stats;
 
+#[cfg(feature = "enable_trace")]
+use crate::my_strategy::trace::Trace;
+
 pub enum Order {
     Idle(Idle),
     Play(Play),
     WalkToGoalkeeperPosition(WalkToGoalkeeperPosition),
+    WalkToPosition(WalkToPosition),
     TakeNitroPack(TakeNitroPack),
     PushOpponent(PushOpponent),
+    BlockLane(BlockLane),
+    Mark(Mark),
 }
 
 impl Order {
-    pub fn try_play(robot: &Robot, world: &World, other: &[Order], max_z: f64, ctx: &mut Context) -> Order {
-        if let Some(play) = Play::try_new(robot, world, other, max_z, ctx) {
+    pub fn try_play(robot: &Robot, world: &World, other: &[Order], zone: Zone, ctx: &mut Context) -> Order {
+        if let Some(play) = Play::try_new(robot, world, other, zone, ctx) {
             Order::Play(play)
         } else {
             Self::idle(robot, world, ctx.order_id_generator)
         }
     }
 
+    pub fn try_play_joint(robot: &Robot, partner: &Robot, world: &World, other: &[Order], zone: Zone, partner_zone: Zone,
+                          ctx: &mut Context) -> (Order, Option<Order>) {
+        if let Some((play, partner_play)) = Play::try_new_with_partner(robot, Some((partner, partner_zone)), world, other, zone, ctx) {
+            (Order::Play(play), partner_play.map(Order::Play))
+        } else {
+            (Self::idle(robot, world, ctx.order_id_generator), None)
+        }
+    }
+
+    pub fn follow_kickoff(robot: &Robot, world: &World, actions: &[Action], order_id_generator: &mut IdGenerator) -> Order {
+        Order::Play(Play::from_kickoff(robot, world, actions, order_id_generator))
+    }
+
     pub fn walk_to_goalkeeper_position(robot: &Robot, world: &World, order_id_generator: &mut IdGenerator) -> Order {
         Order::WalkToGoalkeeperPosition(
             WalkToGoalkeeperPosition::new(robot, world, order_id_generator)
         )
     }
 
-    pub fn try_take_nitro_pack(robot: &Robot, world: &World, max_z: f64, order_id_generator: &mut IdGenerator) -> Order {
-        if let Some(take_nitro_pack) = TakeNitroPack::try_new(robot, world, max_z, order_id_generator) {
+    pub fn walk_to_position(robot: &Robot, world: &World, target: Vec3, name: &'static str, order_id_generator: &mut IdGenerator) -> Order {
+        Order::WalkToPosition(
+            WalkToPosition::new(robot, world, target, name, order_id_generator)
+        )
+    }
+
+    pub fn try_take_nitro_pack(robot: &Robot, world: &World, blackboard: &Blackboard, zone: Zone, order_id_generator: &mut IdGenerator) -> Order {
+        if let Some(take_nitro_pack) = TakeNitroPack::try_new(robot, world, blackboard, zone, order_id_generator) {
             Order::TakeNitroPack(take_nitro_pack)
         } else {
             Self::idle(robot, world, order_id_generator)
         }
     }
 
-    pub fn try_push_opponent(robot: &Robot, world: &World, max_z: f64, order_id_generator: &mut IdGenerator) -> Order {
-        if let Some(push_opponent) = PushOpponent::try_new(robot, world, max_z, order_id_generator) {
+    pub fn try_push_opponent(robot: &Robot, world: &World, zone: Zone, order_id_generator: &mut IdGenerator) -> Order {
+        if let Some(push_opponent) = PushOpponent::try_new(robot, world, zone, order_id_generator) {
             Order::PushOpponent(push_opponent)
         } else {
             Self::idle(robot, world, order_id_generator)
         }
     }
 
+    pub fn try_block_lane(robot: &Robot, world: &World, blackboard: &Blackboard, zone: Zone, order_id_generator: &mut IdGenerator) -> Order {
+        if let Some(block_lane) = BlockLane::try_new(robot, world, blackboard, zone, order_id_generator) {
+            Order::BlockLane(block_lane)
+        } else {
+            Self::idle(robot, world, order_id_generator)
+        }
+    }
+
+    pub fn try_mark(robot: &Robot, world: &World, blackboard: &Blackboard, zone: Zone, order_id_generator: &mut IdGenerator) -> Order {
+        if let Some(mark) = Mark::try_new(robot, world, blackboard, zone, order_id_generator) {
+            Order::Mark(mark)
+        } else {
+            Self::idle(robot, world, order_id_generator)
+        }
+    }
+
+    pub fn commit(previous: Option<&Order>, order: Order, robot: &Robot, world: &World, other: &[Order], zone: Zone,
+                  ctx: &mut Context) -> Order {
+        match previous {
+            Some(Order::Play(previous)) => {
+                if previous.is_jumped(world) && world.rules.is_flying(robot) {
+                    let continued = previous.reevaluate(robot, world, other, zone, ctx)
+                        .unwrap_or_else(|| previous.continued(robot, world, previous.score));
+                    log!(world.game.current_tick, "[{}] <{}> continue jump", robot.id, continued.id);
+                    return Order::Play(continued);
+                }
+                if let Some(continued) = previous.reevaluate(robot, world, other, zone, ctx) {
+                    if order.score() <= continued.score + ctx.config.order_commitment_margin {
+                        log!(
+                            world.game.current_tick, "[{}] <{}> keep order with score {} instead of <{}> with score {}",
+                            robot.id, continued.id, continued.score, order.id(), order.score()
+                        );
+                        return Order::Play(continued);
+                    }
+                }
+            },
+            Some(previous) if !previous.is_idle() && !order.is_idle()
+                && order.score() <= previous.score() + ctx.config.order_commitment_margin => {
+                log!(
+                    world.game.current_tick, "[{}] <{}> keep fallback instead of <{}> with score {}",
+                    robot.id, previous.id(), order.id(), order.score()
+                );
+                return Order::idle(robot, world, ctx.order_id_generator);
+            },
+            _ => (),
+        }
+        order
+    }
+
     pub fn idle(robot: &Robot, world: &World, order_id_generator: &mut IdGenerator) -> Order {
         Order::Idle(Idle::new(robot, world, order_id_generator))
     }
@@ -62,8 +140,11 @@ impl Order {
             Order::Idle(v) => v.id,
             Order::Play(v) => v.id,
             Order::WalkToGoalkeeperPosition(v) => v.id,
+            Order::WalkToPosition(v) => v.id,
             Order::TakeNitroPack(v) => v.id,
             Order::PushOpponent(v) => v.id,
+            Order::BlockLane(v) => v.id,
+            Order::Mark(v) => v.id,
         }
     }
 
@@ -72,8 +153,11 @@ impl Order {
             Order::Idle(v) => v.robot_id,
             Order::Play(v) => v.robot_id,
             Order::WalkToGoalkeeperPosition(v) => v.robot_id,
+            Order::WalkToPosition(v) => v.robot_id,
             Order::TakeNitroPack(v) => v.robot_id,
             Order::PushOpponent(v) => v.robot_id,
+            Order::BlockLane(v) => v.robot_id,
+            Order::Mark(v) => v.robot_id,
         }
     }
 
@@ -82,8 +166,11 @@ impl Order {
             Order::Idle(v) => v.score,
             Order::Play(v) => v.score,
             Order::WalkToGoalkeeperPosition(v) => v.score,
+            Order::WalkToPosition(v) => v.score,
             Order::TakeNitroPack(v) => v.score,
             Order::PushOpponent(v) => v.score,
+            Order::BlockLane(v) => v.score,
+            Order::Mark(v) => v.score,
         }
     }
 
@@ -92,8 +179,11 @@ impl Order {
             Order::Idle(v) => &v.action,
             Order::Play(v) => v.actions.first().unwrap(),
             Order::WalkToGoalkeeperPosition(v) => &v.action,
+            Order::WalkToPosition(v) => &v.action,
             Order::TakeNitroPack(v) => &v.action,
             Order::PushOpponent(v) => &v.action,
+            Order::BlockLane(v) => &v.action,
+            Order::Mark(v) => &v.action,
         }
     }
 
@@ -105,8 +195,11 @@ impl Order {
                 Order::Idle(_) => None,
                 Order::Play(v) => v.action_at(tick),
                 Order::WalkToGoalkeeperPosition(_) => None,
+                Order::WalkToPosition(_) => None,
                 Order::TakeNitroPack(_) => None,
                 Order::PushOpponent(_) => None,
+                Order::BlockLane(_) => None,
+                Order::Mark(_) => None,
             }
         }
     }
@@ -116,8 +209,11 @@ impl Order {
             Order::Idle(_) => None,
             Order::Play(v) => v.time_to_ball,
             Order::WalkToGoalkeeperPosition(_) => None,
+            Order::WalkToPosition(_) => None,
             Order::TakeNitroPack(_) => None,
             Order::PushOpponent(_) => None,
+            Order::BlockLane(_) => None,
+            Order::Mark(_) => None,
         }
     }
 
@@ -133,8 +229,19 @@ impl Order {
             Order::Idle(_) => self,
             Order::Play(v) => Order::Play(v.opposite()),
             Order::WalkToGoalkeeperPosition(v) => Order::WalkToGoalkeeperPosition(v.opposite()),
+            Order::WalkToPosition(v) => Order::WalkToPosition(v.opposite()),
             Order::TakeNitroPack(v) => Order::TakeNitroPack(v.opposite()),
             Order::PushOpponent(v) => Order::PushOpponent(v.opposite()),
+            Order::BlockLane(v) => Order::BlockLane(v.opposite()),
+            Order::Mark(v) => Order::Mark(v.opposite()),
+        }
+    }
+
+    #[cfg(feature = "enable_trace")]
+    pub fn trace(&self) -> Option<&Trace> {
+        match self {
+            Order::Play(v) => v.trace.as_ref(),
+            _ => None,
         }
     }
 
@@ -143,9 +250,12 @@ impl Order {
         match self {
             Order::Play(v) => &v.stats,
             Order::WalkToGoalkeeperPosition(v) => &v.stats,
+            Order::WalkToPosition(v) => &v.stats,
             Order::TakeNitroPack(v) => &v.stats,
             Order::Idle(v) => &v.stats,
             Order::PushOpponent(v) => &v.stats,
+            Order::BlockLane(v) => &v.stats,
+            Order::Mark(v) => &v.stats,
         }
     }
 
@@ -154,9 +264,12 @@ impl Order {
         match self {
             Order::Play(_) => "play",
             Order::WalkToGoalkeeperPosition(_) => "walk_to_goalkeeper_position",
+            Order::WalkToPosition(v) => v.name,
             Order::TakeNitroPack(_) => "take_nitro_pack",
             Order::Idle(_) => "idle",
             Order::PushOpponent(_) => "push_opponent",
+            Order::BlockLane(_) => "block_lane",
+            Order::Mark(_) => "mark",
         }
     }
 
@@ -218,19 +331,30 @@ impl Idle {
 pub struct Play {
     pub id: i32,
     pub robot_id: i32,
+    pub tick: i32,
     pub score: i32,
     pub time_to_ball: Option<f64>,
     pub actions: Vec<Action>,
+    pub pass: Option<Pass>,
+    pub threat: Option<f64>,
+    pub evaluator: Option<Rc<dyn Evaluator>>,
     #[cfg(feature = "enable_render")]
     pub position_to_jump: Option<Vec3>,
     #[cfg(feature = "enable_render")]
     pub history: Vec<Simulator>,
     #[cfg(feature = "enable_stats")]
     pub stats: Stats,
+    #[cfg(feature = "enable_trace")]
+    pub trace: Option<Trace>,
 }
 
 impl Play {
-    pub fn try_new(robot: &Robot, world: &World, other: &[Order], max_z: f64, ctx: &mut Context) -> Option<Self> {
+    pub fn try_new(robot: &Robot, world: &World, other: &[Order], zone: Zone, ctx: &mut Context) -> Option<Self> {
+        Self::try_new_with_partner(robot, None, world, other, zone, ctx).map(|(play, _)| play)
+    }
+
+    pub fn try_new_with_partner(robot: &Robot, partner: Option<(&Robot, Zone)>, world: &World, other: &[Order],
+                                zone: Zone, ctx: &mut Context) -> Option<(Self, Option<Self>)> {
         use crate::my_strategy::plan::Plan;
 
         log!(
@@ -240,19 +364,29 @@ impl Play {
         );
 
         let time_to_play = get_min_time_to_play_ball(other, world);
-        let max_plan_micro_ticks = ctx.config.max_plan_micro_ticks / world.rules.team_size as usize;
+        let max_plan_micro_ticks = ctx.config.max_plan_micro_ticks / world.rules.team_size as usize
+            * if partner.is_some() { 2 } else { 1 };
 
-        let plan = Plan::new(
+        let mut plan = Plan::new(
             ctx.config,
             world.game.current_tick,
             ctx.order_id_generator.next(),
             make_initial_simulator(robot, world),
             time_to_play,
-            max_z,
+            zone,
             make_get_robot_action_at(other),
             max_plan_micro_ticks
                 .min(world.get_micro_ticks_limit() - (*ctx.micro_ticks).min(world.get_micro_ticks_limit())),
-        ).search(ctx.rng);
+        ).with_ball_predictor(world.ball_predictor.clone())
+            .with_opponent_profile(world.opponent_profile.clone());
+
+        plan.use_opponent_model = !world.is_opposite();
+
+        let plan = if let Some((partner, partner_zone)) = partner {
+            plan.with_partner(partner.id, partner_zone)
+        } else {
+            plan
+        }.search(ctx.rng);
 
         *ctx.micro_ticks += plan.used_micro_ticks;
 
@@ -260,18 +394,50 @@ impl Play {
             return None;
         }
 
+        let partner_order = plan.partner.as_ref()
+            .filter(|v| !v.actions.is_empty())
+            .map(|v| Play {
+                id: ctx.order_id_generator.next(),
+                robot_id: v.robot_id,
+                tick: world.game.current_tick,
+                score: plan.score,
+                time_to_ball: None,
+                actions: v.actions.clone(),
+                pass: None,
+                threat: None,
+                evaluator: None,
+                #[cfg(feature = "enable_render")]
+                position_to_jump: None,
+                #[cfg(feature = "enable_render")]
+                history: plan.history.clone(),
+                #[cfg(feature = "enable_stats")]
+                stats: {
+                    let mut stats = Stats::new(robot.player_id, v.robot_id, world.game.current_tick, "play");
+                    stats.order_score = plan.score;
+                    stats
+                },
+                #[cfg(feature = "enable_trace")]
+                trace: None,
+            });
+
         let mut order = Play {
             id: plan.order_id,
             robot_id: robot.id,
+            tick: world.game.current_tick,
             score: plan.score,
             time_to_ball: plan.time_to_ball,
             actions: plan.actions,
+            pass: plan.pass,
+            threat: plan.threat,
+            evaluator: Some(plan.evaluator),
             #[cfg(feature = "enable_render")]
             position_to_jump: None,
             #[cfg(feature = "enable_render")]
             history: plan.history,
             #[cfg(feature = "enable_stats")]
             stats: plan.stats,
+            #[cfg(feature = "enable_trace")]
+            trace: Some(plan.trace),
         };
 
         #[cfg(feature = "enable_stats")]
@@ -284,25 +450,140 @@ impl Play {
             order.stats.other_number = other.len();
         }
 
-        Some(order)
+        Some((order, partner_order))
+    }
+
+    pub fn from_kickoff(robot: &Robot, world: &World, actions: &[Action], order_id_generator: &mut IdGenerator) -> Self {
+        let id = order_id_generator.next();
+
+        log!(world.game.current_tick, "[{}] <{}> follow kickoff {} actions", robot.id, id, actions.len());
+
+        Play {
+            id,
+            robot_id: robot.id,
+            tick: world.game.current_tick,
+            score: 0,
+            time_to_ball: None,
+            actions: actions.to_vec(),
+            pass: None,
+            threat: None,
+            evaluator: None,
+            #[cfg(feature = "enable_render")]
+            position_to_jump: None,
+            #[cfg(feature = "enable_render")]
+            history: Vec::new(),
+            #[cfg(feature = "enable_stats")]
+            stats: Stats::new(robot.player_id, robot.id, world.game.current_tick, "kickoff"),
+            #[cfg(feature = "enable_trace")]
+            trace: None,
+        }
     }
 
     pub fn opposite(self) -> Self {
         Play {
             id: self.id,
             robot_id: self.robot_id,
+            tick: self.tick,
             score: self.score,
             time_to_ball: self.time_to_ball,
             actions: self.actions.into_iter().map(|v| v.opposite()).collect(),
+            pass: self.pass.map(|v| Pass { robot_id: v.robot_id, target: v.target.opposite() }),
+            threat: self.threat,
+            evaluator: self.evaluator,
             #[cfg(feature = "enable_render")]
             position_to_jump: self.position_to_jump.map(|v| v.opposite()),
             #[cfg(feature = "enable_render")]
             history: self.history.into_iter().map(|v| v.opposite()).collect(),
             #[cfg(feature = "enable_stats")]
             stats: self.stats,
+            #[cfg(feature = "enable_trace")]
+            trace: self.trace,
         }
     }
 
+    pub fn reevaluate(&self, robot: &Robot, world: &World, other: &[Order], zone: Zone, ctx: &mut Context) -> Option<Self> {
+        use crate::my_strategy::plan::Plan;
+
+        let offset = (world.game.current_tick - self.tick) as usize;
+
+        if offset >= self.actions.len() {
+            return None;
+        }
+
+        let max_plan_micro_ticks = (ctx.config.max_plan_micro_ticks / world.rules.team_size as usize)
+            .min(world.get_micro_ticks_limit() - (*ctx.micro_ticks).min(world.get_micro_ticks_limit()));
+
+        if max_plan_micro_ticks == 0 {
+            return None;
+        }
+
+        let mut plan = Plan::new(
+            ctx.config,
+            world.game.current_tick,
+            self.id,
+            make_initial_simulator(robot, world),
+            get_min_time_to_play_ball(other, world),
+            zone,
+            make_get_robot_action_at(other),
+            max_plan_micro_ticks,
+        ).with_ball_predictor(world.ball_predictor.clone())
+            .with_opponent_profile(world.opponent_profile.clone());
+
+        if let Some(evaluator) = self.evaluator.as_ref() {
+            plan = plan.with_evaluator(evaluator.clone());
+        }
+
+        plan.pass = self.pass.clone();
+        plan.threat = self.threat;
+        plan.evaluate_threat = false;
+        plan.use_opponent_model = !world.is_opposite();
+
+        let result = plan.follow(self.actions[offset..].to_vec(), ctx.rng);
+
+        *ctx.micro_ticks += result.used_micro_ticks;
+
+        log!(world.game.current_tick, "[{}] <{}> reevaluate order {} -> {}", robot.id, self.id, self.score, result.score);
+
+        Some(self.continued(robot, world, result.score))
+    }
+
+    pub fn continued(&self, robot: &Robot, world: &World, score: i32) -> Self {
+        let offset = (world.game.current_tick - self.tick) as usize;
+        let actions = if offset < self.actions.len() {
+            self.actions[offset..].to_vec()
+        } else {
+            self.actions.last().into_iter().cloned().collect()
+        };
+
+        #[cfg(not(feature = "enable_stats"))]
+        let _ = robot;
+
+        Play {
+            id: self.id,
+            robot_id: self.robot_id,
+            tick: world.game.current_tick,
+            score,
+            time_to_ball: self.time_to_ball.map(|v| v - offset as f64 * world.rules.tick_time_interval()),
+            actions,
+            pass: self.pass.clone(),
+            threat: self.threat,
+            evaluator: self.evaluator.clone(),
+            #[cfg(feature = "enable_render")]
+            position_to_jump: self.position_to_jump,
+            #[cfg(feature = "enable_render")]
+            history: Vec::new(),
+            #[cfg(feature = "enable_stats")]
+            stats: Stats::new(robot.player_id, robot.id, world.game.current_tick, "continue_play"),
+            #[cfg(feature = "enable_trace")]
+            trace: None,
+        }
+    }
+
+    pub fn is_jumped(&self, world: &World) -> bool {
+        let offset = ((world.game.current_tick - self.tick) as usize).min(self.actions.len());
+        self.actions[0..offset].iter().any(|v| v.jump_speed > 0.0)
+    }
+
     pub fn action_at(&self, tick: i32) -> Option<&Action> {
         if 0 <= tick && (tick as usize) < self.actions.len() {
             Some(&self.actions[tick as usize])
@@ -355,7 +636,16 @@ pub struct WalkToGoalkeeperPosition {
 
 impl WalkToGoalkeeperPosition {
     pub fn new(robot: &Robot, world: &World, order_id_generator: &mut IdGenerator) -> Self {
-        let target = world.rules.get_goalkeeper_position(world.game.ball.position());
+        let target = if world.config.use_goalkeeper_coverage_position {
+            world.rules.get_goalkeeper_coverage_position(
+                world.game.ball.position(),
+                world.game.ball.velocity(),
+                world.config.goalkeeper_coverage_shot_speed,
+                world.config.goalkeeper_coverage_prediction_time,
+            )
+        } else {
+            world.rules.get_goalkeeper_position(world.game.ball.position())
+        };
         let to_target = target - robot.position();
         let velocity = if to_target.norm() > world.rules.min_running_distance() {
             to_target.normalized() * world.rules.ROBOT_MAX_GROUND_SPEED
@@ -386,6 +676,235 @@ impl WalkToGoalkeeperPosition {
     }
 }
 
+pub struct WalkToPosition {
+    pub id: i32,
+    pub robot_id: i32,
+    pub action: Action,
+    pub score: i32,
+    pub name: &'static str,
+    #[cfg(feature = "enable_stats")]
+    pub stats: Stats,
+}
+
+impl WalkToPosition {
+    pub fn new(robot: &Robot, world: &World, target: Vec3, name: &'static str, order_id_generator: &mut IdGenerator) -> Self {
+        let to_target = target - robot.position();
+        let velocity = if to_target.norm() > world.rules.min_running_distance() {
+            to_target.normalized() * world.rules.ROBOT_MAX_GROUND_SPEED
+        } else {
+            to_target
+        };
+        let mut action = Action::default();
+        action.set_target_velocity(velocity);
+        WalkToPosition {
+            id: order_id_generator.next(),
+            robot_id: robot.id,
+            action,
+            score: 0,
+            name,
+            #[cfg(feature = "enable_stats")]
+            stats: Stats::new(robot.player_id, robot.id, world.game.current_tick, name),
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        WalkToPosition {
+            id: self.id,
+            robot_id: self.robot_id,
+            action: self.action.opposite(),
+            score: self.score,
+            name: self.name,
+            #[cfg(feature = "enable_stats")]
+            stats: self.stats,
+        }
+    }
+}
+
+pub struct BlockLane {
+    pub id: i32,
+    pub robot_id: i32,
+    pub action: Action,
+    pub score: i32,
+    #[cfg(feature = "enable_stats")]
+    pub stats: Stats,
+}
+
+impl BlockLane {
+    pub fn try_new(robot: &Robot, world: &World, blackboard: &Blackboard, zone: Zone, order_id_generator: &mut IdGenerator) -> Option<Self> {
+        use crate::my_strategy::common::as_score;
+        use crate::my_strategy::line2::Line2;
+        use crate::my_strategy::vec2::Vec2;
+        use crate::my_strategy::nitro_economy::get_arrival_time;
+        use crate::my_strategy::blackboard::Intention;
+
+        let is_blocked_by_teammate = world.game.robots.iter()
+            .any(|v| {
+                v.is_teammate
+                    && v.id != robot.id
+                    && blackboard.get_intention(v.id) == Some(Intention::BlockLane)
+            });
+
+        if is_blocked_by_teammate {
+            return None;
+        }
+
+        let current_ball_position = world.game.ball.position();
+        let goal_position = world.rules.get_my_goal_target().with_y(world.rules.ROBOT_RADIUS);
+
+        let (opponent, time_to_ball) = world.game.robots.iter()
+            .filter(|v| {
+                !v.is_teammate
+                    && v.position().distance(current_ball_position) < world.config.block_lane_max_opponent_distance
+            })
+            .map(|v| {
+                let time_to_ball = blackboard.get_opponent_plan(v.id)
+                    .and_then(|plan| plan.time_to_ball)
+                    .unwrap_or_else(|| get_arrival_time(v, current_ball_position, &world.rules));
+                (v, time_to_ball)
+            })
+            .min_by_key(|(_, time)| as_score(*time))?;
+
+        let ball_position = get_predicted_ball_position(world, time_to_ball);
+
+        if opponent.z <= ball_position.z() {
+            return None;
+        }
+
+        let shot = (goal_position - ball_position).with_y(0.0);
+        let length = shot.norm();
+        let min_distance = world.rules.arena.depth / 8.0;
+
+        if length < 2.0 * min_distance {
+            return None;
+        }
+
+        let direction = shot / length;
+        let normal = Vec2::new(-direction.z(), direction.x());
+        let intersection = Line2::new(ball_position.xz(), goal_position.xz())
+            .possible_intersection(&Line2::new(robot.position().xz(), robot.position().xz() + normal))?;
+        let distance = (Vec3::new(intersection.x(), 0.0, intersection.y()) - ball_position.with_y(0.0)).dot(direction)
+            .max(min_distance)
+            .min(length - min_distance);
+        let target = ball_position.with_y(world.rules.ROBOT_RADIUS) + direction * distance;
+
+        if !zone.contains(target) {
+            return None;
+        }
+
+        let mut action = Action::default();
+        action.set_target_velocity(world.rules.get_target_velocity(target - robot.position()));
+
+        Some(BlockLane {
+            id: order_id_generator.next(),
+            robot_id: robot.id,
+            action,
+            score: 0,
+            #[cfg(feature = "enable_stats")]
+            stats: Stats::new(robot.player_id, robot.id, world.game.current_tick, "block_lane"),
+        })
+    }
+
+    pub fn opposite(self) -> Self {
+        BlockLane {
+            id: self.id,
+            robot_id: self.robot_id,
+            action: self.action.opposite(),
+            score: self.score,
+            #[cfg(feature = "enable_stats")]
+            stats: self.stats,
+        }
+    }
+}
+
+pub fn get_predicted_ball_position(world: &World, time: f64) -> Vec3 {
+    use crate::my_strategy::physics::MoveEquation;
+
+    let tick = (time / world.rules.tick_time_interval()).round() as i32;
+
+    world.ball_predictor.as_ref()
+        .and_then(|v| v.get(tick))
+        .map(|v| v.position)
+        .unwrap_or_else(|| {
+            let position = MoveEquation::from_ball(&world.game.ball, &world.rules).get_position(time);
+            position.with_y(position.y().max(world.rules.BALL_RADIUS))
+        })
+}
+
+pub struct Mark {
+    pub id: i32,
+    pub robot_id: i32,
+    pub opponent_id: i32,
+    pub action: Action,
+    pub score: i32,
+    #[cfg(feature = "enable_stats")]
+    pub stats: Stats,
+}
+
+impl Mark {
+    pub fn try_new(robot: &Robot, world: &World, blackboard: &Blackboard, zone: Zone, order_id_generator: &mut IdGenerator) -> Option<Self> {
+        use crate::my_strategy::common::as_score;
+        use crate::my_strategy::physics::MoveEquation;
+
+        let goal_position = world.rules.get_my_goal_target().with_y(world.rules.ROBOT_RADIUS);
+        let ball_position = world.game.ball.position();
+        let nearest_to_ball = world.game.robots.iter()
+            .filter(|v| !v.is_teammate)
+            .min_by_key(|v| as_score(v.position().distance(ball_position)))
+            .map(|v| v.id);
+
+        let candidates: Vec<(&Robot, Vec3)> = world.game.robots.iter()
+            .filter(|v| {
+                !v.is_teammate
+                    && Some(v.id) != nearest_to_ball
+                    && !blackboard.is_marked(v.id, robot.id)
+            })
+            .map(|v| {
+                let position = MoveEquation::from_robot(v, &world.rules)
+                    .get_position(world.config.mark_prediction_time)
+                    .with_y(world.rules.ROBOT_RADIUS);
+                let to_goal = (goal_position - position).with_y(0.0);
+                let distance = to_goal.norm();
+                if distance > world.config.mark_distance {
+                    (v, position + to_goal * (world.config.mark_distance / distance))
+                } else {
+                    (v, goal_position)
+                }
+            })
+            .filter(|(_, target)| zone.contains(*target))
+            .collect();
+
+        let previous_opponent_id = blackboard.get_previous_mark(robot.id);
+        let (opponent, target) = candidates.iter()
+            .find(|(v, _)| Some(v.id) == previous_opponent_id)
+            .or_else(|| candidates.iter().min_by_key(|(_, target)| as_score(target.distance(goal_position))))?;
+
+        let mut action = Action::default();
+        action.set_target_velocity(world.rules.get_target_velocity(*target - robot.position()));
+
+        Some(Mark {
+            id: order_id_generator.next(),
+            robot_id: robot.id,
+            opponent_id: opponent.id,
+            action,
+            score: 0,
+            #[cfg(feature = "enable_stats")]
+            stats: Stats::new(robot.player_id, robot.id, world.game.current_tick, "mark"),
+        })
+    }
+
+    pub fn opposite(self) -> Self {
+        Mark {
+            id: self.id,
+            robot_id: self.robot_id,
+            opponent_id: self.opponent_id,
+            action: self.action.opposite(),
+            score: self.score,
+            #[cfg(feature = "enable_stats")]
+            stats: self.stats,
+        }
+    }
+}
+
 pub struct Context<'r> {
     pub config: &'r Config,
     pub rng: &'r mut XorShiftRng,
@@ -409,6 +928,7 @@ fn make_initial_simulator(robot: &Robot, world: &World) -> Simulator {
 pub struct TakeNitroPack {
     pub id: i32,
     pub robot_id: i32,
+    pub nitro_pack_id: i32,
     pub action: Action,
     pub score: i32,
     #[cfg(feature = "enable_stats")]
@@ -416,28 +936,33 @@ pub struct TakeNitroPack {
 }
 
 impl TakeNitroPack {
-    pub fn try_new(robot: &Robot, world: &World, max_z: f64, order_id_generator: &mut IdGenerator) -> Option<Self> {
+    pub fn try_new(robot: &Robot, world: &World, blackboard: &Blackboard, zone: Zone, order_id_generator: &mut IdGenerator) -> Option<Self> {
         use crate::my_strategy::common::as_score;
 
-        world.game.nitro_packs.iter()
-            .filter(|v| {
-                v.z < max_z && v.respawn_ticks.is_none()
-            })
-            .map(|v| (v.position().distance(robot.position()), v))
-            .filter(|(distance, _)| *distance < world.rules.arena.depth / 2.0)
-            .min_by_key(|(distance, _)| as_score(*distance))
-            .map(|(_, nitro_pack)| {
-                let to_target = nitro_pack.position() - robot.position();
-                let velocity = if to_target.norm() > world.rules.min_running_distance() {
-                    to_target.normalized() * world.rules.ROBOT_MAX_GROUND_SPEED
-                } else {
-                    to_target * world.rules.ROBOT_MAX_GROUND_SPEED / world.rules.min_running_distance()
-                };
+        let nitro_pack = if world.config.use_nitro_economy {
+            blackboard.get_assigned_nitro_pack_id(robot.id)
+                .and_then(|id| world.game.nitro_packs.iter().find(|v| v.id == id))
+                .filter(|v| !blackboard.is_nitro_pack_claimed(v.id, robot.id))
+        } else {
+            world.game.nitro_packs.iter()
+                .filter(|v| {
+                    zone.contains(v.position()) && v.respawn_ticks.is_none()
+                        && !blackboard.is_nitro_pack_claimed(v.id, robot.id)
+                })
+                .map(|v| (v.position().distance(robot.position()), v))
+                .filter(|(distance, _)| *distance < world.rules.arena.depth / 2.0)
+                .min_by_key(|(distance, _)| as_score(*distance))
+                .map(|(_, nitro_pack)| nitro_pack)
+        };
+
+        nitro_pack
+            .map(|nitro_pack| {
                 let mut action = Action::default();
-                action.set_target_velocity(velocity);
+                action.set_target_velocity(world.rules.get_target_velocity(nitro_pack.position() - robot.position()));
                 TakeNitroPack {
                     id: order_id_generator.next(),
                     robot_id: robot.id,
+                    nitro_pack_id: nitro_pack.id,
                     action,
                     score: 0,
                     #[cfg(feature = "enable_stats")]
@@ -450,6 +975,7 @@ impl TakeNitroPack {
         TakeNitroPack {
             id: self.id,
             robot_id: self.robot_id,
+            nitro_pack_id: self.nitro_pack_id,
             action: self.action.opposite(),
             score: self.score,
             #[cfg(feature = "enable_stats")]
@@ -468,13 +994,13 @@ pub struct PushOpponent {
 }
 
 impl PushOpponent {
-    pub fn try_new(robot: &Robot, world: &World, max_z: f64, order_id_generator: &mut IdGenerator) -> Option<Self> {
+    pub fn try_new(robot: &Robot, world: &World, zone: Zone, order_id_generator: &mut IdGenerator) -> Option<Self> {
         use crate::my_strategy::common::as_score;
 
         world.game.robots.iter()
             .filter(|v| {
                 !v.is_teammate
-                    && v.position().z() < max_z
+                    && zone.contains(v.position())
                     && v.position().distance(world.game.ball.position()) < 10.0
             })
             .min_by_key(|v| {
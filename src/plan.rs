@@ -1,3 +1,4 @@
+use std::rc::Rc;
 use crate::model::Action;
 use crate::my_strategy::random::XorShiftRng;
 use crate::my_strategy::search::{Search, Visitor, Identifiable};
@@ -6,17 +7,27 @@ use crate::my_strategy::common::IdGenerator;
 use crate::my_strategy::vec3::Vec3;
 use crate::my_strategy::config::Config;
 use crate::my_strategy::solid::SolidId;
+use crate::my_strategy::evaluation::Evaluator;
+use crate::my_strategy::zone::Zone;
+use crate::my_strategy::ball_predictor::BallPredictor;
+use crate::my_strategy::opponent_profile::OpponentProfile;
 use crate::my_strategy::scenarios::{
     Jump,
     FarJump,
     WatchMeJump,
+    AerialIntercept,
     WalkToPosition,
     Observe,
     PushRobot,
     WatchBallMove,
+    FollowActions,
     WalkToBall,
     WalkToRobot,
+    WalkOnWall,
+    JumpFromWall,
     PushBall,
+    Partner,
+    PartnerBehaviour,
     Context as ScenarioContext,
     Result as ScenarioResult,
     Error as ScenarioError
@@ -25,16 +36,20 @@ use crate::my_strategy::scenarios::{
 #[cfg(feature = "enable_stats")]
 use crate::my_strategy::stats::Stats;
 
+#[cfg(feature = "enable_trace")]
+use crate::my_strategy::trace::{Trace, Node as TraceNode};
+
 #[derive(Clone)]
 pub struct Plan<'c, 'a, G>
     where G: Clone + Fn(i32, i32) -> Option<&'a Action> {
 
     pub config: &'c Config,
+    pub evaluator: Rc<dyn Evaluator>,
     pub current_tick: i32,
     pub order_id: i32,
     pub simulator: Simulator,
     pub time_to_play: f64,
-    pub max_z: f64,
+    pub zone: Zone,
     pub get_robot_action_at: G,
     pub my_time_to_ball: Option<f64>,
     pub opponent_time_to_ball: Option<f64>,
@@ -44,12 +59,28 @@ pub struct Plan<'c, 'a, G>
     pub path_micro_ticks: usize,
     pub max_plan_micro_ticks: usize,
     pub adaptive_near_micro_ticks_per_tick: usize,
+    pub jump_origin: Option<Rc<JumpOrigin<'c, 'a, G>>>,
+    pub partner: Option<Partner>,
+    pub pass: Option<Pass>,
+    pub evaluate_threat: bool,
+    pub use_opponent_model: bool,
+    pub threat: Option<f64>,
+    pub ball_predictor: Option<Rc<BallPredictor>>,
+    pub opponent_profile: Option<Rc<OpponentProfile>>,
     #[cfg(feature = "enable_render")]
     pub history: Vec<Simulator>,
     #[cfg(feature = "enable_stats")]
     pub stats: Stats,
 }
 
+pub struct JumpOrigin<'c, 'a, G>
+    where G: Clone + Fn(i32, i32) -> Option<&'a Action> {
+
+    pub plan: Plan<'c, 'a, G>,
+    pub target: Vec3,
+    pub max_speed: f64,
+}
+
 pub struct Result {
     pub transitions: Vec<Transition>,
     pub order_id: i32,
@@ -59,18 +90,26 @@ pub struct Result {
     pub time_to_goal: Option<f64>,
     pub actions: Vec<Action>,
     pub used_micro_ticks: usize,
+    pub partner: Option<Partner>,
+    pub pass: Option<Pass>,
+    pub threat: Option<f64>,
+    pub evaluator: Rc<dyn Evaluator>,
     #[cfg(feature = "enable_render")]
     pub history: Vec<Simulator>,
     #[cfg(feature = "enable_stats")]
     pub stats: Stats,
+    #[cfg(feature = "enable_trace")]
+    pub trace: Trace,
 }
 
 impl<'c, 'a, G> Plan<'c, 'a, G>
     where G: Clone + Fn(i32, i32) -> Option<&'a Action> {
 
     pub fn new(config: &'c Config, current_tick: i32, order_id: i32, simulator: Simulator,
-               time_to_play: f64, max_z: f64, get_robot_action_at: G,
+               time_to_play: f64, zone: Zone, get_robot_action_at: G,
                max_plan_micro_ticks: usize) -> Self {
+        use crate::my_strategy::evaluation::make_evaluator;
+
         Plan {
             #[cfg(feature = "enable_stats")]
             stats: {
@@ -79,13 +118,14 @@ impl<'c, 'a, G> Plan<'c, 'a, G>
                 Stats::new(player_id, robot_id, current_tick, "play")
             },
             adaptive_near_micro_ticks_per_tick: config.near_micro_ticks_per_tick,
+            evaluator: make_evaluator(config),
             config,
             max_plan_micro_ticks,
             current_tick,
             order_id,
             simulator,
             time_to_play,
-            max_z,
+            zone,
             get_robot_action_at,
             my_time_to_ball: None,
             opponent_time_to_ball: None,
@@ -93,11 +133,39 @@ impl<'c, 'a, G> Plan<'c, 'a, G>
             position_to_jump: None,
             actions: Vec::new(),
             path_micro_ticks: 0,
+            jump_origin: None,
+            partner: None,
+            pass: None,
+            evaluate_threat: config.threat_penalty_weight > 0.0,
+            use_opponent_model: true,
+            threat: None,
+            ball_predictor: None,
+            opponent_profile: None,
             #[cfg(feature = "enable_render")]
             history: Vec::new(),
         }
     }
 
+    pub fn with_evaluator(mut self, evaluator: Rc<dyn Evaluator>) -> Self {
+        self.evaluator = evaluator;
+        self
+    }
+
+    pub fn with_ball_predictor(mut self, ball_predictor: Option<Rc<BallPredictor>>) -> Self {
+        self.ball_predictor = ball_predictor;
+        self
+    }
+
+    pub fn with_opponent_profile(mut self, opponent_profile: Option<Rc<OpponentProfile>>) -> Self {
+        self.opponent_profile = opponent_profile;
+        self
+    }
+
+    pub fn with_partner(mut self, robot_id: i32, zone: Zone) -> Self {
+        self.partner = Some(Partner::new(robot_id, zone));
+        self
+    }
+
     pub fn search(&self, rng: &mut XorShiftRng) -> Result
         where G: Clone + Fn(i32, i32) -> Option<&'a Action> {
 
@@ -105,28 +173,67 @@ impl<'c, 'a, G> Plan<'c, 'a, G>
 
         let initial_state = visitor.make_initial_state(self.clone());
 
-        let (transitions, final_state, iterations) = Search {
+        let (mut transitions, final_state, iterations) = Search {
             max_iterations: self.config.max_iterations,
         }.perform(initial_state, &mut visitor);
 
+        #[cfg(feature = "enable_trace")]
+        {
+            visitor.trace.player_id = self.simulator.me().player_id();
+            visitor.trace.robot_id = self.simulator.me().id();
+            visitor.trace.current_tick = self.current_tick;
+            visitor.trace.order_id = self.order_id;
+            visitor.trace.final_state_id = final_state.as_ref().map(|v| v.id());
+        }
+
         let plan = final_state.map(|v| v.take_plan())
             .unwrap_or(self.clone());
-        let score = plan.get_score();
+        let plan = visitor.refine_jump(plan, &mut transitions);
+
+        plan.into_result(transitions, visitor, iterations)
+    }
+
+    pub fn follow(&self, actions: Vec<Action>, rng: &mut XorShiftRng) -> Result {
+        let mut visitor = VisitorImpl::new(rng);
+        let mut transitions = vec![Transition::follow_actions(actions)];
+        let initial_state = visitor.make_initial_state(self.clone());
+        let mut state = visitor.use_scenario(&initial_state, &transitions[0]);
+
+        if let State::Hit(_) = state {
+            let transition = Transition::watch_ball_move();
+            state = visitor.use_scenario(&state, &transition);
+            transitions.push(transition);
+        }
+
+        state.take_plan().into_result(transitions, visitor, 0)
+    }
+
+    fn into_result(self, transitions: Vec<Transition>, visitor: VisitorImpl, iterations: usize) -> Result {
+        let score = self.get_score();
+
+        #[cfg(not(feature = "enable_stats"))]
+        let _ = iterations;
 
         Result {
             transitions,
             score,
-            order_id: plan.order_id,
-            simulator: plan.simulator,
-            time_to_ball: plan.my_time_to_ball,
-            time_to_goal: plan.time_to_goal,
-            actions: plan.actions,
+            order_id: self.order_id,
+            simulator: self.simulator,
+            time_to_ball: self.my_time_to_ball,
+            time_to_goal: self.time_to_goal,
+            actions: self.actions,
             used_micro_ticks: visitor.used_micro_ticks,
+            partner: self.partner,
+            pass: self.pass,
+            threat: self.threat,
+            evaluator: self.evaluator,
+            #[cfg(feature = "enable_trace")]
+            trace: visitor.trace,
             #[cfg(feature = "enable_render")]
-            history: plan.history,
+            history: self.history,
             #[cfg(feature = "enable_stats")]
             stats: {
-                let mut stats = plan.stats;
+                let mut stats = self.stats;
                 stats.order_score = score;
                 stats.total_iterations = iterations;
                 stats
@@ -136,61 +243,76 @@ impl<'c, 'a, G> Plan<'c, 'a, G>
 
     pub fn get_score(&self) -> i32 {
         use crate::my_strategy::common::as_score;
-        use crate::my_strategy::entity::Entity;
+
+        as_score(self.get_value())
+    }
+
+    pub fn get_value(&self) -> f64 {
+        use crate::my_strategy::evaluation::Outcome;
 
         let rules = self.simulator.rules();
         let max_time = (self.config.max_ticks + 1) as f64 * rules.tick_time_interval();
-        let ball = self.simulator.ball();
-        let me = self.simulator.me();
-        let to_goal = rules.get_goal_target() - ball.position();
-
-        let ball_goal_distance_score = if self.simulator.score() == 0 {
-            1.0 - to_goal.norm() / rules.arena.max_distance()
-        } else if self.simulator.score() > 0 {
-            2.0
+        let target = if let Some(pass) = self.pass.as_ref() {
+            pass.target
         } else {
-            -1.0
+            rules.get_goal_target()
         };
 
-        let ball_goal_direction_score = if ball.velocity().norm() > 0.0 {
-            (to_goal.cos(ball.velocity()) + 1.0) / 2.0
-        } else {
-            0.0
+        let outcome = Outcome {
+            simulator: &self.simulator,
+            target,
+            max_time,
+            my_time_to_ball: self.my_time_to_ball,
+            opponent_time_to_ball: self.opponent_time_to_ball,
+            time_to_goal: self.time_to_goal,
         };
 
-        let my_time_to_ball_score = if let Some(v) = self.my_time_to_ball {
-            1.0 - v / max_time
-        } else {
-            0.0
-        };
+        let value = self.evaluator.evaluate(&outcome);
 
-        let time_to_goal_score = if let Some(v) = self.time_to_goal {
-            if self.simulator.score() > 0 {
-                1.0 - v / max_time
-            } else {
-                v / max_time
-            }
+        let value = if let Some(pass) = self.pass.as_ref() {
+            value - (1.0 - self.get_pass_score(pass, max_time)) * value.abs()
         } else {
-            0.0
+            value
         };
 
-        let opponent_time_to_ball_penalty = if let Some(v) = self.opponent_time_to_ball {
-            1.0 - v / max_time
+        let threat_penalty = self.threat.unwrap_or(0.0) * self.config.threat_penalty_weight;
+
+        value - threat_penalty
+    }
+
+    pub fn get_pass_score(&self, pass: &Pass, max_time: f64) -> f64 {
+        use crate::my_strategy::entity::Entity;
+
+        let weights_sum = self.config.pass_teammate_time_to_ball_score_weight
+            + self.config.pass_follow_up_shot_score_weight;
+
+        if self.simulator.score() != 0 || weights_sum <= 0.0 {
+            return 1.0;
+        }
+
+        let rules = self.simulator.rules();
+        let ball = self.simulator.ball();
+        let teammate = self.simulator.get_robot(pass.robot_id);
+        let to_ball = ball.position() - teammate.position();
+        let speed = if to_ball.norm() > 0.0 {
+            teammate.velocity().dot(to_ball.normalized())
         } else {
             0.0
         };
+        let teammate_time_to_ball = self.simulator.current_time()
+            + rules.time_for_distance(speed, to_ball.norm());
 
-        let nitro_amount_score = me.nitro_amount() / rules.MAX_NITRO_AMOUNT;
+        let teammate_time_to_ball_score = 1.0 - (teammate_time_to_ball / max_time).min(1.0);
 
-        let total = 0.0
-            + ball_goal_distance_score * self.config.ball_goal_distance_score_weight
-            + ball_goal_direction_score * self.config.ball_goal_direction_score_weight
-            + my_time_to_ball_score * self.config.my_time_to_ball_score_weight
-            + time_to_goal_score * self.config.time_to_goal_score_weight
-            - opponent_time_to_ball_penalty * self.config.opponent_time_to_ball_penalty_weight
-            + nitro_amount_score * self.config.nitro_amount_score_weight;
+        let to_goal = rules.get_goal_target() - ball.position();
+        let follow_up_shot_score = (1.0 - to_goal.norm() / rules.arena.max_distance())
+            * (to_goal.cos(to_ball) + 1.0) / 2.0;
+
+        let score = 0.0
+            + teammate_time_to_ball_score * self.config.pass_teammate_time_to_ball_score_weight
+            + follow_up_shot_score * self.config.pass_follow_up_shot_score_weight;
 
-        as_score(total)
+        (score / weights_sum).max(0.0).min(1.0)
     }
 }
 
@@ -198,6 +320,10 @@ pub struct VisitorImpl<'r> {
     rng: &'r mut XorShiftRng,
     state_id_generator: IdGenerator,
     used_micro_ticks: usize,
+    #[cfg(feature = "enable_trace")]
+    trace: Trace,
+    #[cfg(feature = "enable_trace")]
+    last_error: Option<ScenarioError>,
 }
 
 impl<'r> VisitorImpl<'r> {
@@ -206,13 +332,30 @@ impl<'r> VisitorImpl<'r> {
             rng,
             state_id_generator: IdGenerator::new(),
             used_micro_ticks: 0,
+            #[cfg(feature = "enable_trace")]
+            trace: Trace::default(),
+            #[cfg(feature = "enable_trace")]
+            last_error: None,
         }
     }
 
     pub fn make_initial_state<'c, 'a, G>(&mut self, plan: Plan<'c, 'a, G>) -> State<'c, 'a, G>
         where G: Clone + Fn(i32, i32) -> Option<&'a Action> {
 
-        State::initial(self.state_id_generator.next(), plan)
+        let state = State::initial(self.state_id_generator.next(), plan);
+
+        #[cfg(feature = "enable_trace")]
+        self.trace.nodes.push(TraceNode {
+            id: state.id(),
+            parent_id: None,
+            transition: None,
+            state: state.name().to_string(),
+            score: state.score(),
+            time: state.plan().simulator.current_time(),
+            error: None,
+        });
+
+        state
     }
 
     pub fn get_transitions_for_initial_state<'c, 'a, G>(state: &Final<'c, 'a, G>) -> Vec<Transition>
@@ -220,6 +363,12 @@ impl<'r> VisitorImpl<'r> {
 
         use crate::my_strategy::entity::Entity;
 
+        if let Some(partner) = state.plan.partner.as_ref() {
+            if partner.behaviour.is_none() {
+                return Self::get_partner_transitions(&state.plan.simulator, partner.zone);
+            }
+        }
+
         if state.plan.simulator.rules().is_flying(state.plan.simulator.me().base()) {
             let mut result = vec![
                 Transition::watch_me_jump(0.0, false),
@@ -231,6 +380,8 @@ impl<'r> VisitorImpl<'r> {
                 result.push(Transition::watch_me_jump(state.plan.simulator.rules().ROBOT_MAX_JUMP_SPEED, true));
             }
 
+            Self::try_add_aerial_intercept(&state.plan, &mut result);
+
             result
         } else {
             let mut result = Vec::new();
@@ -245,8 +396,9 @@ impl<'r> VisitorImpl<'r> {
                 result.push(Transition::far_jump(false));
             }
 
-            result.push(Transition::observe(0, state.plan.time_to_play, state.plan.max_z));
+            result.push(Transition::observe(0, state.plan.time_to_play, state.plan.zone));
 
+            Self::try_add_pass(&state.plan, &mut result);
             Self::try_add_push_robot(&state.plan.simulator, &state.plan, &mut result);
             Self::try_add_take_nitro_pack(&state.plan, &mut result);
 
@@ -254,6 +406,59 @@ impl<'r> VisitorImpl<'r> {
         }
     }
 
+    pub fn get_partner_transitions(simulator: &Simulator, zone: Zone) -> Vec<Transition> {
+        use crate::my_strategy::entity::Entity;
+
+        let goalkeeper_position = simulator.rules().get_goalkeeper_position(simulator.ball().position());
+
+        let mut result = vec![
+            Transition::partner(PartnerBehaviour::Free),
+            Transition::partner(PartnerBehaviour::HoldPosition(goalkeeper_position)),
+        ];
+
+        if zone.contains(simulator.ball().position()) {
+            result.push(Transition::partner(PartnerBehaviour::Intercept { allow_jump: false }));
+            result.push(Transition::partner(PartnerBehaviour::Intercept { allow_jump: true }));
+        }
+
+        result
+    }
+
+    pub fn try_add_aerial_intercept<'c, 'a, G>(plan: &Plan<'c, 'a, G>, transitions: &mut Vec<Transition>)
+        where G: Clone + Fn(i32, i32) -> Option<&'a Action>  {
+
+        let nitro_amount = plan.simulator.me().nitro_amount();
+
+        if !plan.config.use_aerial_control || nitro_amount <= 0.0 {
+            return;
+        }
+
+        for fraction in plan.config.aerial_nitro_budget_fractions.iter() {
+            transitions.push(Transition::aerial_intercept(nitro_amount * fraction));
+        }
+    }
+
+    pub fn try_add_pass<'c, 'a, G>(plan: &Plan<'c, 'a, G>, transitions: &mut Vec<Transition>)
+        where G: Clone + Fn(i32, i32) -> Option<&'a Action>  {
+
+        use crate::my_strategy::entity::Entity;
+
+        if !plan.config.use_passes || plan.pass.is_some() || plan.simulator.current_tick() > 0 {
+            return;
+        }
+
+        let rules = plan.simulator.rules();
+        let me = plan.simulator.me();
+
+        plan.simulator.robots().iter()
+            .filter(|v| v.is_teammate() && v.id() != me.id())
+            .for_each(|v| {
+                let predicted = v.position() + v.velocity() * plan.config.pass_prediction_time;
+                let target = rules.arena.projected_with_shift(predicted, rules.BALL_RADIUS);
+                transitions.push(Transition::pass(v.id(), target));
+            });
+    }
+
     pub fn try_add_push_robot<'c, 'a, G>(simulator: &Simulator, plan: &Plan<'c, 'a, G>, transitions: &mut Vec<Transition>)
         where G: Clone + Fn(i32, i32) -> Option<&'a Action>  {
 
@@ -272,7 +477,7 @@ impl<'r> VisitorImpl<'r> {
         simulator.robots().iter()
             .filter(|v| {
                 !v.is_teammate()
-                && v.position().z() < plan.max_z
+                && plan.zone.contains(v.position())
                 && v.position().distance(ball.position()) < rules.arena.depth / 8.0
                 && v.position().distance(me.position()) < rules.arena.depth / 8.0
             })
@@ -303,7 +508,7 @@ impl<'r> VisitorImpl<'r> {
 
         plan.simulator.nitro_packs().iter()
             .filter(|v| {
-                v.z < plan.max_z && v.respawn_ticks.is_none()
+                plan.zone.contains(v.position()) && v.respawn_ticks.is_none()
             })
             .map(|v| (v.position().distance(me.position()), v))
             .filter(|(distance, _)| *distance < rules.arena.depth / 6.0)
@@ -336,14 +541,9 @@ impl<'r> VisitorImpl<'r> {
             observe_simulator.current_micro_tick()
         );
 
-        let mut result = get_points(observe_simulator, state.plan.current_tick, self.rng).into_iter()
+        let mut result = get_points(observe_simulator, state.plan.pass.as_ref().map(|v| v.target), state.plan.config, state.plan.current_tick, self.rng).into_iter()
             .map(|point| {
-                let position_to_jump = {
-                    let mut robot = observe_simulator.me().clone();
-                    robot.set_position(point);
-                    rules.arena.collide(&mut robot);
-                    robot.position()
-                };
+                let position_to_jump = get_position_to_jump(observe_simulator, point);
                 let to_target = position_to_jump - observe_simulator.me().position();
                 let distance_to_target = to_target.norm();
                 let max_speed = if observe_simulator.current_time() > 0.0 {
@@ -369,6 +569,13 @@ impl<'r> VisitorImpl<'r> {
             result.push(Transition::walk_to_ball(-to_ball.with_neg_x(), true));
         }
 
+        if state.plan.config.use_wall_play
+            && observe_simulator.ball().distance_to_arena() < rules.max_robot_jump_height()
+            && observe_simulator.ball().normal_to_arena().y() < state.plan.config.wall_play_max_normal_y {
+            let target = observe_simulator.ball().projected_to_arena_position_with_shift(rules.ROBOT_RADIUS);
+            result.push(Transition::walk_on_wall(target));
+        }
+
         result
     }
 
@@ -400,6 +607,10 @@ impl<'r> VisitorImpl<'r> {
 
         let mut result = vec![Transition::jump(SolidId::Ball, false)];
 
+        if state.plan.config.use_aerial_control && state.plan.simulator.me().nitro_amount() > 0.0 {
+            result.push(Transition::jump(SolidId::Ball, true));
+        }
+
         if state.plan.simulator.rules().team_size <= 2
             && state.plan.simulator.current_tick() <= 10
             && !state.plan.simulator.rules().is_near_my_goal(state.plan.simulator.me().position()) {
@@ -452,6 +663,38 @@ impl<'r> VisitorImpl<'r> {
         }
     }
 
+    pub fn set_partner_behaviour<'c, 'a, G>(&mut self, state: &State<'c, 'a, G>, behaviour: &PartnerBehaviour) -> State<'c, 'a, G>
+        where G: Clone + Fn(i32, i32) -> Option<&'a Action> {
+
+        let mut plan = state.plan().clone();
+
+        log!(
+            plan.current_tick, "[{}] <{}> <{}> set partner behaviour {:?}",
+            plan.simulator.me().id(), plan.order_id, state.id(), behaviour
+        );
+
+        if let Some(partner) = plan.partner.as_mut() {
+            partner.behaviour = Some(behaviour.clone());
+        }
+
+        State::initial(self.state_id_generator.next(), plan)
+    }
+
+    pub fn set_pass<'c, 'a, G>(&mut self, state: &State<'c, 'a, G>, pass: &Pass) -> State<'c, 'a, G>
+        where G: Clone + Fn(i32, i32) -> Option<&'a Action> {
+
+        let mut plan = state.plan().clone();
+
+        log!(
+            plan.current_tick, "[{}] <{}> <{}> set pass {:?}",
+            plan.simulator.me().id(), plan.order_id, state.id(), pass
+        );
+
+        plan.pass = Some(pass.clone());
+
+        State::initial(self.state_id_generator.next(), plan)
+    }
+
     pub fn use_scenario<'c, 'a, G>(&mut self, state: &State<'c, 'a, G>, transition: &Transition) -> State<'c, 'a, G>
         where G: Clone + Fn(i32, i32) -> Option<&'a Action> {
 
@@ -467,6 +710,8 @@ impl<'r> VisitorImpl<'r> {
         );
 
         let path_micro_ticks_before = plan.path_micro_ticks;
+        let max_path_micro_ticks = path_micro_ticks_before
+            + plan.max_plan_micro_ticks.max(self.used_micro_ticks) - self.used_micro_ticks;
 
         plan.adaptive_near_micro_ticks_per_tick = match transition {
             Transition::WalkToPosition(v) => {
@@ -482,11 +727,19 @@ impl<'r> VisitorImpl<'r> {
                 }
             },
             Transition::WalkToBall(_) => plan.config.far_micro_ticks_per_tick,
+            Transition::WalkOnWall(_) => plan.config.far_micro_ticks_per_tick,
             _ => plan.adaptive_near_micro_ticks_per_tick,
         };
 
         if let Transition::WalkToPosition(v) = transition {
             plan.position_to_jump = Some(v.target);
+            if plan.config.max_jump_refinement_iterations > 0 {
+                plan.jump_origin = Some(Rc::new(JumpOrigin {
+                    plan: state.plan().clone(),
+                    target: v.target,
+                    max_speed: v.max_speed,
+                }));
+            }
             log!(
                 plan.current_tick, "[{}] <{}> <{}> use position to jump {}:{}:{} {:?}",
                 plan.simulator.me().id(), plan.order_id, state.id(),
@@ -512,10 +765,13 @@ impl<'r> VisitorImpl<'r> {
             far_micro_ticks_per_tick: plan.config.far_micro_ticks_per_tick,
             used_path_micro_ticks: &mut plan.path_micro_ticks,
             max_path_micro_ticks: match transition {
-                Transition::Observe(_) => plan.max_plan_micro_ticks.max(self.used_micro_ticks) - self.used_micro_ticks,
-                _ => plan.config.max_path_micro_ticks,
+                Transition::Observe(_) => max_path_micro_ticks,
+                _ => plan.config.max_path_micro_ticks.min(max_path_micro_ticks),
             },
             config: plan.config,
+            partner: plan.partner.as_mut(),
+            ball_predictor: plan.ball_predictor.as_ref().map(|v| v.as_ref()),
+            use_opponent_model: plan.use_opponent_model,
             #[cfg(feature = "enable_render")]
             history: &mut plan.history,
             #[cfg(feature = "enable_stats")]
@@ -527,6 +783,9 @@ impl<'r> VisitorImpl<'r> {
         self.used_micro_ticks += *ctx.used_path_micro_ticks - path_micro_ticks_before;
 
         if self.used_micro_ticks >= plan.max_plan_micro_ticks {
+            if let Err(ScenarioError::MicroTicksLimit) = result {
+                plan.actions.clear();
+            }
             State::end(self.state_id_generator.next(), plan)
         } else {
             match result {
@@ -534,16 +793,28 @@ impl<'r> VisitorImpl<'r> {
                     Transition::WalkToPosition(_) => State::walked_to_ball(self.state_id_generator.next(), plan),
                     Transition::Jump(_) => State::jumped(self.state_id_generator.next(), plan),
                     Transition::FarJump(_) => State::far_jumped(self.state_id_generator.next(), plan),
-                    Transition::WatchMeJump(_) => State::hit(self.state_id_generator.next(), plan),
+                    Transition::WatchMeJump(_) => {
+                        plan.threat = self.get_threat(&plan);
+                        State::hit(self.state_id_generator.next(), plan)
+                    },
+                    Transition::AerialIntercept(_) => {
+                        plan.threat = self.get_threat(&plan);
+                        State::hit(self.state_id_generator.next(), plan)
+                    },
                     Transition::WatchBallMove(_) => State::end(self.state_id_generator.next(), plan),
+                    Transition::FollowActions(_) => State::hit(self.state_id_generator.next(), plan),
                     Transition::PushRobot(_) => State::initial(self.state_id_generator.next(), plan),
                     Transition::TakeNitroPack(_) => State::initial(self.state_id_generator.next(), plan),
                     Transition::WalkToBall(_) => State::walked_to_ball(self.state_id_generator.next(), plan),
                     Transition::WalkToRobot(v) => State::walked_to_robot(self.state_id_generator.next(), plan, v.robot_id),
                     Transition::PushBall(_) => State::walked_to_ball(self.state_id_generator.next(), plan),
+                    Transition::WalkOnWall(_) => State::walked_on_wall(self.state_id_generator.next(), plan),
+                    Transition::JumpFromWall(_) => State::jumped(self.state_id_generator.next(), plan),
                     Transition::Observe(_) => unimplemented!(),
                     Transition::ForkBall(_) => unimplemented!(),
                     Transition::ForkRobot(_) => unimplemented!(),
+                    Transition::Partner(_) => unimplemented!(),
+                    Transition::Pass(_) => unimplemented!(),
                 },
                 Err(error) => {
                     match (transition, error) {
@@ -562,6 +833,10 @@ impl<'r> VisitorImpl<'r> {
                                 state.plan().simulator.current_time(), state.plan().simulator.current_tick(),
                                 state.plan().simulator.current_micro_tick(), error
                             );
+                            #[cfg(feature = "enable_trace")]
+                            {
+                                self.last_error = Some(error);
+                            }
                             State::end(self.state_id_generator.next(), plan)
                         }
                     }
@@ -569,6 +844,182 @@ impl<'r> VisitorImpl<'r> {
             }
         }
     }
+
+    pub fn get_threat<'c, 'a, G>(&mut self, plan: &Plan<'c, 'a, G>) -> Option<f64>
+        where G: Clone + Fn(i32, i32) -> Option<&'a Action> {
+
+        use crate::my_strategy::entity::Entity;
+        use crate::my_strategy::common::as_score;
+
+        if !plan.evaluate_threat || plan.simulator.score() != 0 {
+            return None;
+        }
+
+        let max_plan_micro_ticks = plan.config.threat_max_plan_micro_ticks
+            .min(plan.max_plan_micro_ticks.max(self.used_micro_ticks) - self.used_micro_ticks);
+
+        if max_plan_micro_ticks == 0 {
+            return None;
+        }
+
+        let ball_position = plan.simulator.ball().position();
+        let opponent_id = plan.simulator.robots().iter()
+            .filter(|v| !v.is_teammate() && !v.ignore())
+            .min_by_key(|v| as_score(v.position().distance(ball_position)))
+            .map(|v| v.id())?;
+
+        let get_robot_action_at: fn(i32, i32) -> Option<&'a Action> = get_no_robot_action_at;
+        let mut opponent_plan = Plan::new(
+            plan.config,
+            plan.current_tick,
+            plan.order_id,
+            plan.simulator.clone().opposite().with_me(opponent_id),
+            0.0,
+            Zone::from(std::f64::MAX),
+            get_robot_action_at,
+            max_plan_micro_ticks,
+        ).with_evaluator(plan.evaluator.clone());
+        opponent_plan.evaluate_threat = false;
+        opponent_plan.use_opponent_model = !plan.use_opponent_model;
+
+        let mut visitor = VisitorImpl::new(self.rng);
+        let initial_state = visitor.make_initial_state(opponent_plan);
+
+        let (_, final_state, _) = Search {
+            max_iterations: plan.config.threat_max_iterations,
+        }.perform(initial_state, &mut visitor);
+
+        self.used_micro_ticks += visitor.used_micro_ticks;
+
+        let threat_factor = plan.opponent_profile.as_ref()
+            .map(|v| v.get_threat_factor(opponent_id, plan.config, plan.simulator.rules()))
+            .unwrap_or(1.0);
+        let threat = final_state.map(|v| v.take_plan().get_value().max(0.0) * threat_factor);
+
+        log!(
+            plan.current_tick, "[{}] <{}> threat from opponent {} is {:?}",
+            plan.simulator.me().id(), plan.order_id, opponent_id, threat
+        );
+
+        threat
+    }
+
+    pub fn refine_jump<'c, 'a, G>(&mut self, plan: Plan<'c, 'a, G>, transitions: &mut Vec<Transition>) -> Plan<'c, 'a, G>
+        where G: Clone + Fn(i32, i32) -> Option<&'a Action> {
+
+        use crate::my_strategy::entity::Entity;
+        use crate::my_strategy::optimization::minimize_nelder_mead;
+
+        let origin = match plan.jump_origin.clone() {
+            Some(v) => v,
+            None => return plan,
+        };
+
+        let walk_to_position = transitions.iter()
+            .rposition(|v| matches!(v, Transition::WalkToPosition(_)));
+
+        let walk_to_position = match walk_to_position {
+            Some(v) => v,
+            None => return plan,
+        };
+
+        let tail = transitions[walk_to_position + 1..].to_vec();
+        let rules = origin.plan.simulator.rules();
+        let time_interval = rules.tick_time_interval();
+        let time_to_jump = origin.plan.simulator.me().position().distance(origin.target)
+            / origin.max_speed.max(1e-3);
+        let mut best_score = plan.get_score();
+        let mut best = None;
+
+        log!(
+            plan.current_tick, "[{}] <{}> refine jump target={:?} time_to_jump={} tail={:?} score={}",
+            plan.simulator.me().id(), plan.order_id, origin.target, time_to_jump, tail, best_score
+        );
+
+        minimize_nelder_mead(
+            &[origin.target.x(), origin.target.z(), time_to_jump],
+            &[rules.ROBOT_RADIUS, rules.ROBOT_RADIUS, 2.0 * time_interval],
+            plan.config.max_jump_refinement_iterations,
+            |x| {
+                let target = Vec3::new(x[0], origin.target.y(), x[1]);
+                match self.try_jump(&origin.plan, target, x[2].max(time_interval), &tail) {
+                    Some((path, candidate)) => {
+                        let score = candidate.get_score();
+                        if score > best_score {
+                            best_score = score;
+                            best = Some((path, candidate));
+                        }
+                        -score as f64
+                    },
+                    None => std::f64::MAX,
+                }
+            }
+        );
+
+        if let Some((path, candidate)) = best {
+            log!(
+                plan.current_tick, "[{}] <{}> use refined jump {:?} score={}",
+                plan.simulator.me().id(), plan.order_id, path, best_score
+            );
+            transitions.truncate(walk_to_position);
+            transitions.extend(path);
+            candidate
+        } else {
+            plan
+        }
+    }
+
+    pub fn try_jump<'c, 'a, G>(&mut self, plan: &Plan<'c, 'a, G>, target: Vec3, time_to_jump: f64,
+                               tail: &[Transition]) -> Option<(Vec<Transition>, Plan<'c, 'a, G>)>
+        where G: Clone + Fn(i32, i32) -> Option<&'a Action> {
+
+        use crate::my_strategy::entity::Entity;
+
+        if self.used_micro_ticks >= plan.max_plan_micro_ticks {
+            return None;
+        }
+
+        let rules = plan.simulator.rules();
+        let position_to_jump = get_position_to_jump(&plan.simulator, target);
+        let max_speed = (position_to_jump.distance(plan.simulator.me().position()) / time_to_jump)
+            .min(rules.ROBOT_MAX_GROUND_SPEED);
+        let path: Vec<Transition> = std::iter::once(Transition::walk_to_position(position_to_jump, max_speed))
+            .chain(tail.iter().cloned())
+            .collect();
+
+        let mut state = State::initial(self.state_id_generator.next(), plan.clone());
+
+        for (n, transition) in path.iter().enumerate() {
+            state = self.perform(&state, transition);
+
+            #[cfg(feature = "enable_stats")]
+            state.plan_mut().stats.path.push(transition.name());
+
+            if let State::End(_) = state {
+                if n + 1 < path.len() {
+                    return None;
+                }
+            }
+        }
+
+        if state.is_final() {
+            Some((path, state.take_plan()))
+        } else {
+            None
+        }
+    }
+
+    pub fn perform<'c, 'a, G>(&mut self, state: &State<'c, 'a, G>, transition: &Transition) -> State<'c, 'a, G>
+        where G: Clone + Fn(i32, i32) -> Option<&'a Action> {
+
+        match transition {
+            Transition::ForkBall(_) => self.fork(state),
+            Transition::ForkRobot(_) => self.fork(state),
+            Transition::Partner(v) => self.set_partner_behaviour(state, v),
+            Transition::Pass(v) => self.set_pass(state, v),
+            _ => self.use_scenario(state, transition),
+        }
+    }
 }
 
 impl<'r, 'c, 'a, G> Visitor<State<'c, 'a, G>, Transition> for VisitorImpl<'r>
@@ -583,22 +1034,31 @@ impl<'r, 'c, 'a, G> Visitor<State<'c, 'a, G>, Transition> for VisitorImpl<'r>
             State::Initial(v) => Self::get_transitions_for_initial_state(v),
             State::ObservedBall(v) => vec![
                 Transition::fork_ball(),
-                Transition::observe(v.number + 1, v.plan.time_to_play, v.plan.max_z),
+                Transition::observe(v.number + 1, v.plan.time_to_play, v.plan.zone),
             ],
             State::ObservedRobot(v) => vec![
                 Transition::fork_robot(v.robot_id),
-                Transition::observe(v.number, v.plan.time_to_play, v.plan.max_z),
+                Transition::observe(v.number, v.plan.time_to_play, v.plan.zone),
             ],
             State::ForkedBall(v) => self.get_transitions_for_forked_ball_state(v),
             State::ForkedRobot(v) => self.get_transitions_for_forked_robot_state(v),
             State::WalkedToBall(v) => self.get_transitions_for_walked_to_ball_state(v),
             State::WalkedToRobot(v) => vec![Transition::jump(SolidId::Robot(v.robot_id), true)],
-            State::Jumped(v) => vec![
-                Transition::watch_me_jump(v.plan.simulator.rules().ROBOT_MAX_JUMP_SPEED, false)
-            ],
-            State::FarJumped(v) => vec![
-                Transition::watch_me_jump(v.plan.simulator.rules().ROBOT_MAX_JUMP_SPEED, false)
-            ],
+            State::WalkedOnWall(_) => vec![Transition::jump_from_wall()],
+            State::Jumped(v) => {
+                let mut result = vec![
+                    Transition::watch_me_jump(v.plan.simulator.rules().ROBOT_MAX_JUMP_SPEED, false)
+                ];
+                Self::try_add_aerial_intercept(&v.plan, &mut result);
+                result
+            },
+            State::FarJumped(v) => {
+                let mut result = vec![
+                    Transition::watch_me_jump(v.plan.simulator.rules().ROBOT_MAX_JUMP_SPEED, false)
+                ];
+                Self::try_add_aerial_intercept(&v.plan, &mut result);
+                result
+            },
             State::Hit(_) => vec![Transition::watch_ball_move()],
             State::End(_) => Vec::new(),
         };
@@ -616,11 +1076,12 @@ impl<'r, 'c, 'a, G> Visitor<State<'c, 'a, G>, Transition> for VisitorImpl<'r>
     }
 
     fn apply(&mut self, iteration: usize, state: &State<'c, 'a, G>, transition: &Transition) -> State<'c, 'a, G> {
-        let mut result = match transition {
-            Transition::ForkBall(_) => self.fork(state),
-            Transition::ForkRobot(_) => self.fork(state),
-            _ => self.use_scenario(state, transition),
-        };
+        #[cfg(feature = "enable_trace")]
+        {
+            self.last_error = None;
+        }
+
+        let mut result = self.perform(state, transition);
 
         log!(
             state.plan().current_tick, "[{}] <{}> <{}> transition {}:{}:{} {:?} -> <{}> {:?}",
@@ -635,6 +1096,17 @@ impl<'r, 'c, 'a, G> Visitor<State<'c, 'a, G>, Transition> for VisitorImpl<'r>
             result.plan_mut().stats.path.push(transition.name());
         }
 
+        #[cfg(feature = "enable_trace")]
+        self.trace.nodes.push(TraceNode {
+            id: result.id(),
+            parent_id: Some(state.id()),
+            transition: Some(transition.name().to_string()),
+            state: result.name().to_string(),
+            score: result.score(),
+            time: result.plan().simulator.current_time(),
+            error: self.last_error.take().map(|v| format!("{:?}", v)),
+        });
+
         result
     }
 
@@ -642,6 +1114,8 @@ impl<'r, 'c, 'a, G> Visitor<State<'c, 'a, G>, Transition> for VisitorImpl<'r>
         match transition {
             Transition::ForkBall(_) => 0,
             Transition::ForkRobot(_) => 0,
+            Transition::Partner(_) => 0,
+            Transition::Pass(_) => 0,
             Transition::Observe(_) => 1,
             _ => source_state.score() - destination_state.score(),
         }
@@ -663,6 +1137,7 @@ pub enum State<'c, 'a, G>
     ForkedRobot(ForkedRobot<'c, 'a, G>),
     WalkedToBall(Final<'c, 'a, G>),
     WalkedToRobot(WalkedToRobot<'c, 'a, G>),
+    WalkedOnWall(Final<'c, 'a, G>),
     Jumped(Final<'c, 'a, G>),
     FarJumped(Final<'c, 'a, G>),
     Hit(Final<'c, 'a, G>),
@@ -700,6 +1175,10 @@ impl<'c, 'a, G> State<'c, 'a, G>
         State::WalkedToRobot(WalkedToRobot { id, score: plan.get_score(), plan, robot_id })
     }
 
+    pub fn walked_on_wall(id: i32, plan: Plan<'c, 'a, G>) -> Self {
+        State::WalkedOnWall(Final { id, score: plan.get_score(), plan })
+    }
+
     pub fn jumped(id: i32, plan: Plan<'c, 'a, G>) -> Self {
         State::Jumped(Final { id, score: plan.get_score(), plan })
     }
@@ -725,6 +1204,7 @@ impl<'c, 'a, G> State<'c, 'a, G>
             State::ForkedRobot(v) => v.id,
             State::WalkedToBall(v) => v.id,
             State::WalkedToRobot(v) => v.id,
+            State::WalkedOnWall(v) => v.id,
             State::Jumped(v) => v.id,
             State::FarJumped(v) => v.id,
             State::Hit(v) => v.id,
@@ -741,6 +1221,7 @@ impl<'c, 'a, G> State<'c, 'a, G>
             State::ForkedRobot(v) => v.score,
             State::WalkedToBall(v) => v.score,
             State::WalkedToRobot(v) => v.score,
+            State::WalkedOnWall(v) => v.score,
             State::Jumped(v) => v.score,
             State::FarJumped(v) => v.score,
             State::Hit(v) => v.score,
@@ -757,6 +1238,7 @@ impl<'c, 'a, G> State<'c, 'a, G>
             State::ForkedRobot(v) => &v.plan,
             State::WalkedToBall(v) => &v.plan,
             State::WalkedToRobot(v) => &v.plan,
+            State::WalkedOnWall(v) => &v.plan,
             State::Jumped(v) => &v.plan,
             State::FarJumped(v) => &v.plan,
             State::Hit(v) => &v.plan,
@@ -773,6 +1255,7 @@ impl<'c, 'a, G> State<'c, 'a, G>
             State::ForkedRobot(v) => &mut v.plan,
             State::WalkedToBall(v) => &mut v.plan,
             State::WalkedToRobot(v) => &mut v.plan,
+            State::WalkedOnWall(v) => &mut v.plan,
             State::Jumped(v) => &mut v.plan,
             State::FarJumped(v) => &mut v.plan,
             State::Hit(v) => &mut v.plan,
@@ -789,6 +1272,7 @@ impl<'c, 'a, G> State<'c, 'a, G>
             State::ForkedRobot(v) => v.plan,
             State::WalkedToBall(v) => v.plan,
             State::WalkedToRobot(v) => v.plan,
+            State::WalkedOnWall(v) => v.plan,
             State::Jumped(v) => v.plan,
             State::FarJumped(v) => v.plan,
             State::Hit(v) => v.plan,
@@ -805,6 +1289,7 @@ impl<'c, 'a, G> State<'c, 'a, G>
             State::ForkedRobot(_) => "ForkedRobot",
             State::WalkedToBall(_) => "WalkedToBall",
             State::WalkedToRobot(_) => "WalkedToRobot",
+            State::WalkedOnWall(_) => "WalkedOnWall",
             State::Jumped(_) => "Jumped",
             State::FarJumped(_) => "FarJumped",
             State::Hit(_) => "Hit",
@@ -916,17 +1401,23 @@ pub enum Transition {
     Jump(Jump),
     FarJump(FarJump),
     WatchMeJump(WatchMeJump),
+    AerialIntercept(AerialIntercept),
     WatchBallMove(WatchBallMove),
+    FollowActions(FollowActions),
     PushRobot(PushRobot),
     TakeNitroPack(WalkToPosition),
     WalkToBall(WalkToBall),
     WalkToRobot(WalkToRobot),
+    WalkOnWall(WalkOnWall),
+    JumpFromWall(JumpFromWall),
     PushBall(PushBall),
+    Partner(PartnerBehaviour),
+    Pass(Pass),
 }
 
 impl Transition {
-    pub fn observe(number: usize, wait_until: f64, max_ball_z: f64) -> Self {
-        Transition::Observe(Observe { number, wait_until, max_z: max_ball_z })
+    pub fn observe(number: usize, wait_until: f64, zone: Zone) -> Self {
+        Transition::Observe(Observe { number, wait_until, zone })
     }
 
     pub fn fork_ball() -> Self {
@@ -953,10 +1444,18 @@ impl Transition {
         Transition::WatchMeJump(WatchMeJump { jump_speed, allow_nitro })
     }
 
+    pub fn aerial_intercept(nitro_budget: f64) -> Self {
+        Transition::AerialIntercept(AerialIntercept { nitro_budget })
+    }
+
     pub fn watch_ball_move() -> Self {
         Transition::WatchBallMove(WatchBallMove {})
     }
 
+    pub fn follow_actions(actions: Vec<Action>) -> Self {
+        Transition::FollowActions(FollowActions { actions })
+    }
+
     pub fn push_robot(robot_id: i32, allow_nitro: bool, until_time: f64) -> Self {
         Transition::PushRobot(PushRobot { robot_id, allow_nitro, until_time })
     }
@@ -973,10 +1472,26 @@ impl Transition {
         Transition::WalkToRobot(WalkToRobot { robot_id, direction, allow_nitro })
     }
 
+    pub fn walk_on_wall(target: Vec3) -> Self {
+        Transition::WalkOnWall(WalkOnWall { target })
+    }
+
+    pub fn jump_from_wall() -> Self {
+        Transition::JumpFromWall(JumpFromWall {})
+    }
+
     pub fn push_ball(until_time: f64) -> Self {
         Transition::PushBall(PushBall { until_time })
     }
 
+    pub fn partner(behaviour: PartnerBehaviour) -> Self {
+        Transition::Partner(behaviour)
+    }
+
+    pub fn pass(robot_id: i32, target: Vec3) -> Self {
+        Transition::Pass(Pass { robot_id, target })
+    }
+
     pub fn name(&self) -> &'static str {
         match self {
             Transition::Observe(_) => "observe",
@@ -984,7 +1499,9 @@ impl Transition {
             Transition::Jump(_) => "jump",
             Transition::FarJump(_) => "far_jump",
             Transition::WatchMeJump(_) => "watch_me_jump",
+            Transition::AerialIntercept(_) => "aerial_intercept",
             Transition::WatchBallMove(_) => "watch_ball_move",
+            Transition::FollowActions(_) => "follow_actions",
             Transition::PushRobot(_) => "push_robot",
             Transition::TakeNitroPack(_) => "take_nitro_pack",
             Transition::ForkBall(_) => "fork_ball",
@@ -992,6 +1509,10 @@ impl Transition {
             Transition::WalkToRobot(_) => "walk_to_robot",
             Transition::ForkRobot(_) => "fork_robot",
             Transition::PushBall(_) => "push_ball",
+            Transition::WalkOnWall(_) => "walk_on_wall",
+            Transition::JumpFromWall(_) => "jump_from_wall",
+            Transition::Partner(_) => "partner",
+            Transition::Pass(_) => "pass",
         }
     }
 
@@ -1004,14 +1525,20 @@ impl Transition {
             Transition::Jump(v) => v.perform(ctx),
             Transition::FarJump(v) => v.perform(ctx),
             Transition::WatchMeJump(v) => v.perform(ctx),
+            Transition::AerialIntercept(v) => v.perform(ctx),
             Transition::WatchBallMove(v) => v.perform(ctx),
+            Transition::FollowActions(v) => v.perform(ctx),
             Transition::PushRobot(v) => v.perform(ctx),
             Transition::TakeNitroPack(v) => v.perform(ctx),
             Transition::WalkToBall(v) => v.perform(ctx),
             Transition::WalkToRobot(v) => v.perform(ctx),
             Transition::PushBall(v) => v.perform(ctx),
+            Transition::WalkOnWall(v) => v.perform(ctx),
+            Transition::JumpFromWall(v) => v.perform(ctx),
             Transition::ForkBall(_) => unimplemented!(),
             Transition::ForkRobot(_) => unimplemented!(),
+            Transition::Partner(_) => unimplemented!(),
+            Transition::Pass(_) => unimplemented!(),
         }
     }
 }
@@ -1024,7 +1551,22 @@ pub struct ForkRobot {
     pub robot_id: i32,
 }
 
-pub fn get_points(simulator: &Simulator, current_tick: i32, rng: &mut XorShiftRng) -> Vec<Vec3> {
+#[derive(Debug, Clone)]
+pub struct Pass {
+    pub robot_id: i32,
+    pub target: Vec3,
+}
+
+pub fn get_position_to_jump(simulator: &Simulator, point: Vec3) -> Vec3 {
+    use crate::my_strategy::entity::Entity;
+
+    let mut robot = simulator.me().clone();
+    robot.set_position(point);
+    simulator.rules().arena.collide(&mut robot);
+    robot.position()
+}
+
+pub fn get_points(simulator: &Simulator, target: Option<Vec3>, config: &Config, current_tick: i32, rng: &mut XorShiftRng) -> Vec<Vec3> {
     use crate::my_strategy::physics::get_min_distance_between_spheres;
     use crate::my_strategy::common::Clamp;
     use crate::my_strategy::plane::Plane;
@@ -1036,8 +1578,12 @@ pub fn get_points(simulator: &Simulator, current_tick: i32, rng: &mut XorShiftRn
     let robot = simulator.me();
     let rules = simulator.rules();
 
-    let distance_to_ball = ball.position().distance(robot.position());
-    let time_to_ball = rules.time_for_distance(rules.ROBOT_MAX_GROUND_SPEED, distance_to_ball);
+    let time_to_ball = if let Some(table) = config.reachability_table.as_ref() {
+        table.get_time_to_ball(rules, robot.position(), robot.velocity(), ball.position()).unwrap_or(std::f64::MAX)
+    } else {
+        let distance_to_ball = ball.position().distance(robot.position());
+        rules.time_for_distance(rules.ROBOT_MAX_GROUND_SPEED, distance_to_ball)
+    };
     let max_time_diff = 2.0 * (rules.ROBOT_RADIUS + rules.BALL_RADIUS) / rules.ROBOT_MAX_GROUND_SPEED;
     let number = if time_to_ball < simulator.current_time() + max_time_diff {
         if time_to_ball < rules.tick_time_interval() * 10.0 {
@@ -1077,6 +1623,20 @@ pub fn get_points(simulator: &Simulator, current_tick: i32, rng: &mut XorShiftRn
     }
 
     let mean_distance = (max_distance + min_distance) / 2.0;
+
+    if let Some(target) = target {
+        let to_target = Plane::projected(target - ball.position(), ball.normal_to_arena());
+        if to_target.norm() > 0.0 {
+            let position = base_position - to_target.normalized() * mean_distance;
+            let projected = rules.arena.projected_with_shift(position, rules.ROBOT_MAX_RADIUS);
+            log!(
+                current_tick, "[{}] get_points target={:?} position={:?} projected={:?}",
+                robot.id(), target, position, projected
+            );
+            result.push(projected);
+        }
+    }
+
     for i in 0..number {
         let (angle, distance) = if i % 2 == 0 {
             if i % 4 == 0 {
@@ -1116,3 +1676,7 @@ pub fn get_points(simulator: &Simulator, current_tick: i32, rng: &mut XorShiftRn
 
     result
 }
+
+fn get_no_robot_action_at<'a>(_: i32, _: i32) -> Option<&'a Action> {
+    None
+}
@@ -0,0 +1,161 @@
+use std::rc::Rc;
+use crate::model::Rules;
+use crate::my_strategy::vec3::Vec3;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReachabilityTable {
+    pub speeds: Vec<f64>,
+    pub forward: Vec<f64>,
+    pub lateral: Vec<f64>,
+    pub heights: Vec<f64>,
+    pub ground_times: Vec<f64>,
+    pub jump_times: Vec<f64>,
+    pub max_ground_speed: f64,
+}
+
+#[derive(Deserialize)]
+struct ReachabilityTableData {
+    speeds: Vec<f64>,
+    forward: Vec<f64>,
+    lateral: Vec<f64>,
+    heights: Vec<f64>,
+    ground_times: Vec<f64>,
+    jump_times: Vec<f64>,
+    max_ground_speed: f64,
+}
+
+impl<'de> serde::Deserialize<'de> for ReachabilityTable {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: serde::Deserializer<'de> {
+
+        let data = ReachabilityTableData::deserialize(deserializer)?;
+        let result = ReachabilityTable {
+            speeds: data.speeds,
+            forward: data.forward,
+            lateral: data.lateral,
+            heights: data.heights,
+            ground_times: data.ground_times,
+            jump_times: data.jump_times,
+            max_ground_speed: data.max_ground_speed,
+        };
+        result.validate().map_err(serde::de::Error::custom)?;
+        Ok(result)
+    }
+}
+
+impl ReachabilityTable {
+    pub fn validate(&self) -> Result<(), String> {
+        use std::cmp::Ordering;
+
+        for (name, values) in [
+            ("speeds", &self.speeds),
+            ("forward", &self.forward),
+            ("lateral", &self.lateral),
+            ("heights", &self.heights),
+        ].iter() {
+            if values.len() < 2 {
+                return Err(format!("{} should have at least 2 values but has {}", name, values.len()));
+            }
+            if values.windows(2).any(|v| v[0].partial_cmp(&v[1]) != Some(Ordering::Less)) {
+                return Err(format!("{} should be strictly increasing", name));
+            }
+        }
+
+        let ground_times_len = self.speeds.len() * self.forward.len() * self.lateral.len();
+
+        if self.ground_times.len() != ground_times_len {
+            return Err(format!("ground_times should have {} values but has {}", ground_times_len, self.ground_times.len()));
+        }
+
+        if self.jump_times.len() != self.heights.len() {
+            return Err(format!("jump_times should have {} values but has {}", self.heights.len(), self.jump_times.len()));
+        }
+
+        if self.max_ground_speed.partial_cmp(&0.0) != Some(Ordering::Greater) {
+            return Err(format!("max_ground_speed should be positive but is {}", self.max_ground_speed));
+        }
+
+        Ok(())
+    }
+
+    pub fn get_time(&self, velocity: Vec3, to_target: Vec3) -> Option<f64> {
+        let ground = to_target.with_y(0.0);
+        let speed = velocity.with_y(0.0).norm();
+        let direction = if speed > 1e-3 {
+            velocity.with_y(0.0) / speed
+        } else if ground.norm() > 0.0 {
+            ground.normalized()
+        } else {
+            Vec3::k()
+        };
+        let forward = ground.dot(direction);
+        let lateral = (ground - direction * forward).norm();
+        let ground_time = self.get_ground_time(speed, forward, lateral)?;
+
+        let jump_time = if to_target.y() > 0.0 {
+            if to_target.y() > *self.heights.last()? {
+                return None;
+            }
+            let (index, weight) = locate(&self.heights, to_target.y())?;
+            let (left, right) = (*self.jump_times.get(index)?, *self.jump_times.get(index + 1)?);
+            if right == f64::MAX {
+                return None;
+            }
+            left + (right - left) * weight
+        } else {
+            0.0
+        };
+
+        Some(ground_time.max(jump_time))
+    }
+
+    pub fn get_time_to_ball(&self, rules: &Rules, position: Vec3, velocity: Vec3, ball_position: Vec3) -> Option<f64> {
+        let to_ball = ball_position - position;
+        self.get_time(velocity, to_ball.with_y((to_ball.y() - rules.BALL_RADIUS).max(0.0)))
+    }
+
+    fn get_ground_time(&self, speed: f64, forward: f64, lateral: f64) -> Option<f64> {
+        let clamped_forward = forward.max(*self.forward.first()?).min(*self.forward.last()?);
+        let clamped_lateral = lateral.min(*self.lateral.last()?);
+        let extra_distance = Vec3::new(lateral - clamped_lateral, 0.0, forward - clamped_forward).norm();
+
+        let (s, sw) = locate(&self.speeds, speed)?;
+        let (f, fw) = locate(&self.forward, clamped_forward)?;
+        let (l, lw) = locate(&self.lateral, clamped_lateral)?;
+
+        let mut result = 0.0;
+        for (si, sk) in [(s, 1.0 - sw), (s + 1, sw)].iter() {
+            for (fi, fk) in [(f, 1.0 - fw), (f + 1, fw)].iter() {
+                for (li, lk) in [(l, 1.0 - lw), (l + 1, lw)].iter() {
+                    result += self.get_ground_time_at(*si, *fi, *li)? * sk * fk * lk;
+                }
+            }
+        }
+
+        Some(result + extra_distance / self.max_ground_speed)
+    }
+
+    fn get_ground_time_at(&self, speed: usize, forward: usize, lateral: usize) -> Option<f64> {
+        self.ground_times.get((speed * self.forward.len() + forward) * self.lateral.len() + lateral).cloned()
+    }
+}
+
+fn locate(values: &[f64], value: f64) -> Option<(usize, f64)> {
+    if values.len() < 2 {
+        return None;
+    }
+    let value = value.max(values[0]).min(values[values.len() - 1]);
+    let index = values.iter()
+        .position(|v| *v > value)
+        .unwrap_or(values.len() - 1)
+        .max(1) - 1;
+    let weight = (value - values[index]) / (values[index + 1] - values[index]);
+    Some((index, weight))
+}
+
+pub fn deserialize_reachability_table<'de, D>(deserializer: D) -> Result<Option<Rc<ReachabilityTable>>, D::Error>
+    where D: serde::Deserializer<'de> {
+    use serde::Deserialize;
+
+    Ok(Option::<ReachabilityTable>::deserialize(deserializer)?.map(Rc::new))
+}
@@ -1,16 +1,120 @@
 use crate::model::Robot;
 use crate::my_strategy::world::World;
+use crate::my_strategy::zone::Zone;
 
 #[cfg(feature = "enable_render")]
 use crate::my_strategy::render::{Color, Render};
 
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub enum RoleType {
+    Goalkeeper,
+    Defender,
+    Midfielder,
+    Forward,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Deserialize)]
+pub enum Formation {
+    Classic,
+    Defensive,
+    Balanced,
+}
+
+impl Formation {
+    pub fn get_role_types(&self, robots_number: usize) -> Vec<RoleType> {
+        if robots_number == 0 {
+            Vec::new()
+        } else if robots_number == 1 {
+            if cfg!(feature = "use_single_goalkeeper") {
+                vec![RoleType::Goalkeeper]
+            } else {
+                vec![RoleType::Forward]
+            }
+        } else {
+            let mut result = vec![RoleType::Goalkeeper];
+            if robots_number >= 3 {
+                match self {
+                    Formation::Classic => (),
+                    Formation::Defensive => result.push(RoleType::Defender),
+                    Formation::Balanced => result.push(RoleType::Midfielder),
+                }
+            }
+            result.resize(robots_number, RoleType::Forward);
+            result
+        }
+    }
+}
+
+pub fn get_optimal_roles(robots_ids: &[i32], role_types: &[RoleType], current_roles: &[Role],
+                         world: &World, role_change_gap: i32) -> Vec<Role> {
+    use crate::my_strategy::assignment::{find_max_assignment, INVALID_SCORE};
+
+    let scores = robots_ids.iter()
+        .map(|robot_id| {
+            let current = current_roles.iter().find(|v| v.robot_id() == *robot_id);
+            role_types.iter()
+                .map(|role_type| {
+                    match current {
+                        Some(v) if v.role_type() == *role_type => v.get_score(world),
+                        Some(v) if !v.can_quit(world) => INVALID_SCORE,
+                        _ => Role::new(*role_type, *robot_id).get_score(world),
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let (score, slots) = match find_max_assignment(&scores) {
+        Some(v) => v,
+        None => return Vec::new(),
+    };
+
+    if let Some(current_score) = get_current_roles_score(robots_ids, role_types, current_roles, &scores) {
+        if score <= current_score + role_change_gap {
+            return robots_ids.iter()
+                .filter_map(|robot_id| current_roles.iter().find(|v| v.robot_id() == *robot_id).cloned())
+                .collect();
+        }
+    }
+
+    robots_ids.iter().zip(slots.iter())
+        .map(|(robot_id, slot)| Role::new(role_types[*slot], *robot_id))
+        .collect()
+}
+
+fn get_current_roles_score(robots_ids: &[i32], role_types: &[RoleType], current_roles: &[Role],
+                           scores: &[Vec<i32>]) -> Option<i32> {
+    let mut used = vec![false; role_types.len()];
+    let mut result = 0;
+
+    for (robot_id, robot_scores) in robots_ids.iter().zip(scores.iter()) {
+        let role_type = current_roles.iter().find(|v| v.robot_id() == *robot_id)?.role_type();
+        let slot = (0..role_types.len()).find(|v| !used[*v] && role_types[*v] == role_type)?;
+        used[slot] = true;
+        result += robot_scores[slot];
+    }
+
+    Some(result)
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum Role {
     Forward(Forward),
     Goalkeeper(Goalkeeper),
+    Defender(Defender),
+    Midfielder(Midfielder),
 }
 
 impl Role {
+    pub fn new(role_type: RoleType, robot_id: i32) -> Self {
+        match role_type {
+            RoleType::Forward => Role::forward(robot_id),
+            RoleType::Goalkeeper => Role::goalkeeper(robot_id),
+            RoleType::Defender => Role::defender(robot_id),
+            RoleType::Midfielder => Role::midfielder(robot_id),
+        }
+    }
+
     pub fn forward(robot_id: i32) -> Self {
         Role::Forward(Forward { robot_id })
     }
@@ -19,10 +123,29 @@ impl Role {
         Role::Goalkeeper(Goalkeeper { robot_id })
     }
 
+    pub fn defender(robot_id: i32) -> Self {
+        Role::Defender(Defender { robot_id })
+    }
+
+    pub fn midfielder(robot_id: i32) -> Self {
+        Role::Midfielder(Midfielder { robot_id })
+    }
+
     pub fn get_score(&self, world: &World) -> i32 {
         match self {
             Role::Forward(v) => Forward::get_score(world.get_robot(v.robot_id), world),
             Role::Goalkeeper(v) => Goalkeeper::get_score(world.get_robot(v.robot_id), world),
+            Role::Defender(v) => Defender::get_score(world.get_robot(v.robot_id), world),
+            Role::Midfielder(v) => Midfielder::get_score(world.get_robot(v.robot_id), world),
+        }
+    }
+
+    pub fn role_type(&self) -> RoleType {
+        match self {
+            Role::Forward(_) => RoleType::Forward,
+            Role::Goalkeeper(_) => RoleType::Goalkeeper,
+            Role::Defender(_) => RoleType::Defender,
+            Role::Midfielder(_) => RoleType::Midfielder,
         }
     }
 
@@ -30,13 +153,17 @@ impl Role {
         match self {
             Role::Forward(v) => v.robot_id,
             Role::Goalkeeper(v) => v.robot_id,
+            Role::Defender(v) => v.robot_id,
+            Role::Midfielder(v) => v.robot_id,
         }
     }
 
-    pub fn max_z(&self, world: &World) -> f64 {
+    pub fn zone(&self, world: &World) -> Zone {
         match self {
-            Role::Forward(_) => Forward::max_z(),
-            Role::Goalkeeper(_) => Goalkeeper::max_z(world),
+            Role::Forward(_) => Zone::from(Forward::max_z(world)),
+            Role::Goalkeeper(v) => v.zone(world),
+            Role::Defender(_) => Zone::from(Defender::max_z(world)),
+            Role::Midfielder(_) => Zone::from(Midfielder::max_z(world)),
         }
     }
 
@@ -44,6 +171,8 @@ impl Role {
         match self {
             Role::Forward(_) => Forward::can_quit(),
             Role::Goalkeeper(v) => v.can_quit(world),
+            Role::Defender(v) => v.can_quit(world),
+            Role::Midfielder(_) => Midfielder::can_quit(),
         }
     }
 
@@ -77,6 +206,8 @@ impl Role {
         match self {
             Role::Forward(_) => Forward::get_color(),
             Role::Goalkeeper(_) => Goalkeeper::get_color(),
+            Role::Defender(_) => Defender::get_color(),
+            Role::Midfielder(_) => Midfielder::get_color(),
         }
     }
 
@@ -85,6 +216,8 @@ impl Role {
         match self {
             Role::Forward(_) => "forward",
             Role::Goalkeeper(_) => "goalkeeper",
+            Role::Defender(_) => "defender",
+            Role::Midfielder(_) => "midfielder",
         }
     }
 }
@@ -101,8 +234,16 @@ impl Forward {
         let distance_to_goal_score = 1.0 - world.rules.get_goal_target()
             .distance(robot.position()) / world.rules.arena.max_distance();
 
-        let distance_to_ball_score = 1.0 - world.game.ball.position()
-            .distance(robot.position()) / world.rules.arena.max_distance();
+        let distance_to_ball = if let Some(table) = world.config.reachability_table.as_ref() {
+            table.get_time(robot.velocity(), (world.game.ball.position() - robot.position()).with_y(0.0))
+                .map(|v| v * world.rules.ROBOT_MAX_GROUND_SPEED)
+                .unwrap_or_else(|| world.game.ball.position().distance(robot.position()))
+        } else {
+            world.game.ball.position().distance(robot.position())
+        };
+
+        let distance_to_ball_score = 1.0 - distance_to_ball.min(world.rules.arena.max_distance())
+            / world.rules.arena.max_distance();
 
         as_score((
             0.0
@@ -111,12 +252,8 @@ impl Forward {
         ) / 2.0)
     }
 
-    pub fn max_z() -> f64 {
-        if cfg!(feature = "use_limited_forward") {
-            10.0
-        } else {
-            std::f64::MAX
-        }
+    pub fn max_z(world: &World) -> f64 {
+        world.config.forward_max_z.unwrap_or(f64::MAX)
     }
 
     pub fn can_quit() -> bool {
@@ -149,6 +286,38 @@ impl Goalkeeper {
         (-world.rules.arena.depth / 2.0 + shift).max(-world.rules.NITRO_PACK_Z)
     }
 
+    pub fn zone(&self, world: &World) -> Zone {
+        let zone = Zone::from(Self::max_z(world));
+
+        if !world.config.use_dynamic_goalkeeper_zone
+            || world.game.ball.velocity().norm() > world.config.goalkeeper_zone_max_ball_speed {
+            return zone;
+        }
+
+        let time = world.config.goalkeeper_zone_prediction_time;
+        let ball_position = world.ball_predictor.as_ref()
+            .and_then(|v| v.get((time / world.rules.tick_time_interval()).round() as i32))
+            .map(|v| v.position)
+            .unwrap_or(world.game.ball.position() + world.game.ball.velocity() * time);
+        let radius = world.config.goalkeeper_zone_extension_radius;
+
+        if ball_position.y() > world.rules.max_robot_jump_height()
+            || ball_position.z() - zone.max_z() > radius {
+            return zone;
+        }
+
+        let distance_to_ball = world.get_robot(self.robot_id).position().distance(ball_position);
+        let is_first = world.game.robots.iter()
+            .filter(|v| !v.is_teammate)
+            .all(|v| v.position().distance(ball_position) > distance_to_ball);
+
+        if is_first {
+            zone.with_extension(ball_position.xz(), radius)
+        } else {
+            zone
+        }
+    }
+
     pub fn can_quit(&self, world: &World) -> bool {
         let robot = world.get_robot(self.robot_id);
         world.game.robots.iter()
@@ -166,3 +335,67 @@ impl Goalkeeper {
         Color::new(0.1, 0.8, 0.1, 0.8)
     }
 }
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Defender {
+    pub robot_id: i32,
+}
+
+impl Defender {
+    pub fn get_score(robot: &Robot, world: &World) -> i32 {
+        use crate::my_strategy::common::as_score;
+
+        let distance_to_position_score = 1.0 - world.rules.get_defender_position(world.game.ball.position())
+            .distance(robot.position()) / world.rules.arena.max_distance();
+
+        as_score(distance_to_position_score)
+    }
+
+    pub fn max_z(world: &World) -> f64 {
+        -world.rules.arena.depth / 4.0
+    }
+
+    pub fn can_quit(&self, world: &World) -> bool {
+        let robot = world.get_robot(self.robot_id);
+        world.game.robots.iter()
+            .any(|v| {
+                self.robot_id != v.id
+                    && v.is_teammate
+                    && v.z < robot.z
+            })
+    }
+
+    #[cfg(feature = "enable_render")]
+    pub fn get_color() -> Color {
+        Color::new(0.1, 0.1, 0.8, 0.8)
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Midfielder {
+    pub robot_id: i32,
+}
+
+impl Midfielder {
+    pub fn get_score(robot: &Robot, world: &World) -> i32 {
+        use crate::my_strategy::common::as_score;
+
+        let distance_to_position_score = 1.0 - world.rules.get_midfielder_position(world.game.ball.position())
+            .distance(robot.position()) / world.rules.arena.max_distance();
+
+        as_score(distance_to_position_score)
+    }
+
+    pub fn max_z(world: &World) -> f64 {
+        world.rules.arena.depth / 4.0
+    }
+
+    pub fn can_quit() -> bool {
+        true
+    }
+
+    #[cfg(feature = "enable_render")]
+    pub fn get_color() -> Color {
+        Color::new(0.8, 0.8, 0.1, 0.8)
+    }
+}
@@ -66,6 +66,103 @@ impl Rules {
             .unwrap_or(Vec3::new(0.0, self.ROBOT_RADIUS, -self.arena.depth / 2.0))
     }
 
+    pub fn get_goalkeeper_coverage_position(&self, ball_position: Vec3, ball_velocity: Vec3,
+                                            shot_speed: f64, prediction_time: f64) -> Vec3 {
+        use crate::my_strategy::common::as_score;
+
+        let origin = ball_position + ball_velocity * prediction_time;
+        let speed = ball_velocity.norm().max(shot_speed);
+        let max_x = self.arena.goal_width / 2.0 - self.ROBOT_RADIUS;
+        let min_z = -self.arena.depth / 2.0 + self.ROBOT_RADIUS;
+        let max_z = (-self.arena.depth / 2.0 + self.arena.goal_width / 2.0).min(origin.z());
+
+        let mut candidates = Vec::new();
+        let mut z = min_z;
+        while z <= max_z {
+            let mut x = -max_x;
+            while x <= max_x {
+                candidates.push(Vec3::new(x, self.ROBOT_RADIUS, z));
+                x += 1.0;
+            }
+            z += 1.0;
+        }
+
+        let default = self.get_goalkeeper_position(ball_position);
+
+        candidates.into_iter()
+            .min_by_key(|v| {
+                (as_score(self.get_max_uncovered_shot_angle(origin, speed, *v)), as_score(v.distance(default)))
+            })
+            .unwrap_or(default)
+    }
+
+    pub fn get_max_uncovered_shot_angle(&self, origin: Vec3, speed: f64, position: Vec3) -> f64 {
+        let to_position = (position - origin).with_y(0.0);
+        let min_x = -self.arena.goal_width / 2.0 + self.BALL_RADIUS;
+        let max_y = self.arena.goal_height - self.BALL_RADIUS;
+        let targets_number = 7;
+
+        (0..targets_number)
+            .flat_map(|i| {
+                let x = min_x + (-2.0 * min_x) * i as f64 / (targets_number - 1) as f64;
+                [self.BALL_RADIUS, max_y].iter()
+                    .map(|y| Vec3::new(x, *y, -self.arena.depth / 2.0))
+                    .collect::<Vec<_>>()
+            })
+            .filter(|target| !self.is_shot_covered(origin, *target, speed, position))
+            .map(|target| {
+                let to_target = (target - origin).with_y(0.0);
+                let cos = to_target.dot(to_position) / (to_target.norm() * to_position.norm());
+                cos.clamp(-1.0, 1.0).acos()
+            })
+            .fold(0.0, f64::max)
+    }
+
+    pub fn is_shot_covered(&self, origin: Vec3, target: Vec3, speed: f64, position: Vec3) -> bool {
+        let shot = target - origin;
+        let length = shot.norm();
+        let direction = shot / length;
+        let distance = (position - origin).dot(direction).max(0.0).min(length);
+        let point = origin + direction * distance;
+        let reach = self.ROBOT_RADIUS + self.BALL_RADIUS + self.running_distance(distance / speed);
+
+        (point - position).xz().norm() <= reach
+            && point.y() <= self.max_robot_jump_height() + self.BALL_RADIUS
+    }
+
+    pub fn running_distance(&self, time: f64) -> f64 {
+        use crate::my_strategy::common::Square;
+
+        let acceleration_time = self.min_acceleration_time();
+        if time < acceleration_time {
+            self.ROBOT_ACCELERATION * time.square() / 2.0
+        } else {
+            self.min_running_distance() + self.ROBOT_MAX_GROUND_SPEED * (time - acceleration_time)
+        }
+    }
+
+    pub fn get_defender_position(&self, ball_position: Vec3) -> Vec3 {
+        let far_post_x = if ball_position.x() < 0.0 {
+            self.arena.goal_width / 2.0
+        } else {
+            -self.arena.goal_width / 2.0
+        };
+
+        Vec3::new(
+            far_post_x - far_post_x.signum() * self.ROBOT_RADIUS,
+            self.ROBOT_RADIUS,
+            -self.arena.depth / 2.0 + self.arena.bottom_radius + self.ROBOT_RADIUS
+        )
+    }
+
+    pub fn get_midfielder_position(&self, ball_position: Vec3) -> Vec3 {
+        Vec3::new(
+            (ball_position.x() / 2.0).max(-self.arena.goal_width / 2.0).min(self.arena.goal_width / 2.0),
+            self.ROBOT_RADIUS,
+            0.0
+        )
+    }
+
     pub fn max_robot_jump_height(&self) -> f64 {
         use crate::my_strategy::common::Square;
 
@@ -75,6 +172,55 @@ impl Rules {
             - self.GRAVITY * time.square() / 2.0
     }
 
+    pub fn max_robot_jump_height_with_nitro(&self, nitro_amount: f64) -> f64 {
+        use crate::my_strategy::common::Square;
+
+        let nitro_time = self.nitro_time(nitro_amount);
+        let acceleration = self.ROBOT_NITRO_ACCELERATION - self.GRAVITY;
+        let speed = self.ROBOT_MAX_JUMP_SPEED + acceleration * nitro_time;
+        self.ROBOT_MAX_RADIUS
+            + self.ROBOT_MAX_JUMP_SPEED * nitro_time
+            + acceleration * nitro_time.square() / 2.0
+            + speed.max(0.0).square() / (2.0 * self.GRAVITY)
+    }
+
+    pub fn jump_height_time_with_nitro(&self, height: f64, nitro_amount: f64) -> Option<f64> {
+        use crate::my_strategy::common::Square;
+
+        let height = height - self.ROBOT_MAX_RADIUS;
+
+        if height <= 0.0 {
+            return Some(0.0);
+        }
+
+        let nitro_time = self.nitro_time(nitro_amount);
+        let acceleration = self.ROBOT_NITRO_ACCELERATION - self.GRAVITY;
+
+        if let Some(time) = get_time_to_height(self.ROBOT_MAX_JUMP_SPEED, acceleration, height) {
+            if time <= nitro_time {
+                return Some(time);
+            }
+        }
+
+        let nitro_height = self.ROBOT_MAX_JUMP_SPEED * nitro_time + acceleration * nitro_time.square() / 2.0;
+        let speed = self.ROBOT_MAX_JUMP_SPEED + acceleration * nitro_time;
+
+        get_time_to_height(speed, -self.GRAVITY, height - nitro_height)
+            .map(|time| nitro_time + time)
+    }
+
+    pub fn nitro_time(&self, nitro_amount: f64) -> f64 {
+        nitro_amount * self.NITRO_POINT_VELOCITY_CHANGE / self.ROBOT_NITRO_ACCELERATION
+    }
+
+    pub fn get_target_velocity(&self, to_target: Vec3) -> Vec3 {
+        if to_target.norm() > self.min_running_distance() {
+            to_target.normalized() * self.ROBOT_MAX_GROUND_SPEED
+        } else {
+            to_target * self.ROBOT_MAX_GROUND_SPEED / self.min_running_distance()
+        }
+    }
+
     pub fn min_acceleration_time(&self) -> f64 {
         self.ROBOT_MAX_GROUND_SPEED / self.ROBOT_ACCELERATION
     }
@@ -249,3 +395,18 @@ impl Rules {
     }
 }
 
+fn get_time_to_height(speed: f64, acceleration: f64, height: f64) -> Option<f64> {
+    use crate::my_strategy::common::Square;
+
+    if acceleration.abs() < 1e-9 {
+        return if speed > 0.0 { Some(height / speed) } else { None };
+    }
+
+    let discriminant = speed.square() + 2.0 * acceleration * height;
+
+    if discriminant < 0.0 {
+        return None;
+    }
+
+    Some((discriminant.sqrt() - speed) / acceleration).filter(|v| *v >= 0.0)
+}
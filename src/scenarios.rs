@@ -1,9 +1,11 @@
-use crate::model::Action;
+use crate::model::{Action, Rules};
 use crate::my_strategy::random::XorShiftRng;
 use crate::my_strategy::simulator::Simulator;
 use crate::my_strategy::vec3::Vec3;
 use crate::my_strategy::config::Config;
 use crate::my_strategy::solid::{Solid, SolidId};
+use crate::my_strategy::ball_predictor::BallPredictor;
+use crate::my_strategy::zone::Zone;
 
 #[cfg(feature = "enable_stats")]
 use crate::my_strategy::stats::Stats;
@@ -28,6 +30,9 @@ pub struct Context<'r, 'a, G>
     pub used_path_micro_ticks: &'r mut usize,
     pub max_path_micro_ticks: usize,
     pub config: &'r Config,
+    pub partner: Option<&'r mut Partner>,
+    pub ball_predictor: Option<&'r BallPredictor>,
+    pub use_opponent_model: bool,
     #[cfg(feature = "enable_render")]
     pub history: &'r mut Vec<Simulator>,
     #[cfg(feature = "enable_stats")]
@@ -58,6 +63,65 @@ const ALL: usize = GOAL | LIMITS;
 
 pub type Result = std::result::Result<(), Error>;
 
+#[derive(Debug, Clone)]
+pub enum PartnerBehaviour {
+    Free,
+    HoldPosition(Vec3),
+    Intercept { allow_jump: bool },
+}
+
+#[derive(Debug, Clone)]
+pub struct Partner {
+    pub robot_id: i32,
+    pub zone: Zone,
+    pub behaviour: Option<PartnerBehaviour>,
+    pub actions: Vec<Action>,
+}
+
+impl Partner {
+    pub fn new(robot_id: i32, zone: Zone) -> Self {
+        Partner {
+            robot_id,
+            zone,
+            behaviour: None,
+            actions: Vec::new(),
+        }
+    }
+
+    pub fn get_action(&self, simulator: &Simulator) -> Option<Action> {
+        use crate::my_strategy::entity::Entity;
+
+        let rules = simulator.rules();
+        let robot = simulator.get_robot(self.robot_id);
+        let ball = simulator.ball();
+
+        let (target, jump) = match &self.behaviour {
+            None | Some(PartnerBehaviour::Free) => return None,
+            Some(PartnerBehaviour::HoldPosition(target)) => (*target, false),
+            Some(PartnerBehaviour::Intercept { allow_jump }) => {
+                let jump = *allow_jump
+                    && ball.position().y() > robot.position().y()
+                    && robot.position().distance(ball.position())
+                        < rules.ball_distance_limit() + rules.max_robot_jump_height();
+                let target = ball.projected_to_arena_position_with_shift(rules.ROBOT_RADIUS);
+                if self.zone.contains(target) {
+                    (target, jump)
+                } else {
+                    (target.with_z(target.z().min(self.zone.max_z())), false)
+                }
+            },
+        };
+
+        let mut action = Action::default();
+        action.set_target_velocity(rules.get_target_velocity(target - robot.position()));
+        if jump {
+            action.jump_speed = rules.ROBOT_MAX_JUMP_SPEED;
+        }
+
+        Some(action)
+    }
+}
+
 impl<'r, 'a, G> Context<'r, 'a, G>
     where G: Fn(i32, i32) -> Option<&'a Action> {
 
@@ -72,7 +136,12 @@ impl<'r, 'a, G> Context<'r, 'a, G>
             return Err(Error::TicksLimit);
         }
 
-        if checks & MICRO_TICKS_LIMIT != 0 && *self.used_path_micro_ticks >= self.max_path_micro_ticks {
+        let micro_ticks_per_tick = match tick_type {
+            TickType::Near => self.near_micro_ticks_per_tick,
+            TickType::Far => self.far_micro_ticks_per_tick,
+        };
+
+        if checks & MICRO_TICKS_LIMIT != 0 && *self.used_path_micro_ticks + micro_ticks_per_tick > self.max_path_micro_ticks {
             return Err(Error::MicroTicksLimit);
         }
 
@@ -81,10 +150,23 @@ impl<'r, 'a, G> Context<'r, 'a, G>
         let my_position = self.simulator.me().position();
         let ignore_distance = self.simulator.rules().arena.depth / 2.0;
 
+        let opponent_model = self.config.opponent_model.as_ref().filter(|_| self.use_opponent_model);
+        let predicted_actions: Vec<(i32, Action)> = if let Some(model) = opponent_model {
+            self.simulator.robots().iter()
+                .filter(|v| !v.is_teammate() && (self.get_robot_action_at)(v.id(), current_tick).is_none())
+                .map(|v| (v.id(), model.get_action(v.id(), self.simulator)))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
         for robot in self.simulator.robots_mut().iter_mut() {
             if let Some(action) = (self.get_robot_action_at)(robot.id(), current_tick) {
                 robot.set_ignore(false);
                 *robot.action_mut() = action.clone();
+            } else if let Some((_, action)) = predicted_actions.iter().find(|(id, _)| *id == robot.id()) {
+                robot.set_ignore(false);
+                *robot.action_mut() = action.clone();
             } else if !robot.is_teammate() {
                 let ignore = robot.position().distance(ball_position) > ignore_distance
                     && robot.position().distance(my_position) > ignore_distance;
@@ -92,10 +174,16 @@ impl<'r, 'a, G> Context<'r, 'a, G>
             }
         }
 
-        let micro_ticks_per_tick = match tick_type {
-            TickType::Near => self.near_micro_ticks_per_tick,
-            TickType::Far => self.far_micro_ticks_per_tick,
-        };
+        if let Some(partner) = self.partner.as_mut() {
+            if let Some(action) = partner.get_action(self.simulator) {
+                let robot = self.simulator.robots_mut().iter_mut()
+                    .find(|v| v.id() == partner.robot_id)
+                    .unwrap();
+                robot.set_ignore(false);
+                *robot.action_mut() = action.clone();
+                partner.actions.push(action);
+            }
+        }
 
         let time_interval = self.simulator.rules().tick_time_interval();
 
@@ -104,12 +192,15 @@ impl<'r, 'a, G> Context<'r, 'a, G>
             self.update();
         }
 
-        self.simulator.tick(time_interval, micro_ticks_per_tick, self.rng);
+        if let Some(ball_predictor) = self.get_ball_predictor(micro_ticks_per_tick) {
+            self.simulator.tick_predicted_ball(time_interval, ball_predictor);
+        } else {
+            self.simulator.tick(time_interval, micro_ticks_per_tick, self.rng);
+            *self.used_path_micro_ticks += micro_ticks_per_tick;
+        }
 
         self.update();
 
-        *self.used_path_micro_ticks += micro_ticks_per_tick;
-
         if !self.simulator.ignore_me() {
             self.actions.push(self.simulator.me().action().clone());
         }
@@ -132,6 +223,21 @@ impl<'r, 'a, G> Context<'r, 'a, G>
         Ok(())
     }
 
+    fn get_ball_predictor(&self, micro_ticks_per_tick: usize) -> Option<&'r BallPredictor> {
+        use crate::my_strategy::entity::Entity;
+
+        self.ball_predictor.filter(|v| {
+            v.micro_ticks_per_tick() == micro_ticks_per_tick
+                && self.simulator.robots().iter().all(|v| v.ignore())
+                && v.is_on_trajectory(
+                    self.simulator.current_tick(),
+                    self.simulator.ball().position(),
+                    self.simulator.ball().velocity(),
+                )
+                && v.get(self.simulator.current_tick() + 1).is_some()
+        })
+    }
+
     fn update(&mut self) {
         use crate::my_strategy::simulator::RobotCollisionType;
 
@@ -286,6 +392,104 @@ impl WalkToBall {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct WalkOnWall {
+    pub target: Vec3,
+}
+
+impl WalkOnWall {
+    pub fn perform<'r, 'a, G>(&self, ctx: &mut Context<'r, 'a, G>) -> Result
+        where G: Fn(i32, i32) -> Option<&'a Action> {
+
+        use crate::my_strategy::entity::Entity;
+        use crate::my_strategy::plane::Plane;
+
+        *ctx.simulator.me_mut().action_mut() = Action::default();
+
+        let max_distance_to_target = ctx.simulator.rules().ROBOT_MAX_GROUND_SPEED
+            * ctx.simulator.rules().tick_time_interval();
+
+        log!(
+            ctx.current_tick, "[{}] <{}> <{}> walk on wall {}:{} target={}/{} ball={}",
+            ctx.robot_id, ctx.order_id, ctx.state_id,
+            ctx.simulator.current_time(), ctx.used_path_micro_ticks,
+            ctx.simulator.me().position().distance(self.target), max_distance_to_target,
+            ctx.simulator.me().position().distance(ctx.simulator.ball().position())
+        );
+
+        while !does_jump_hit_ball(false, ctx) {
+            if ctx.simulator.me().position().distance(self.target) <= max_distance_to_target {
+                break;
+            }
+
+            let touch_normal = match ctx.simulator.me().touch_normal() {
+                Some(v) => v,
+                None => return Err(Error::BadCondition),
+            };
+
+            let to_target = Plane::projected(self.target - ctx.simulator.me().position(), touch_normal);
+            let target_velocity = ctx.simulator.rules().get_target_velocity(to_target);
+
+            ctx.simulator.me_mut().action_mut().set_target_velocity(target_velocity);
+            ctx.simulator.me_mut().action_mut().use_nitro = false;
+
+            ctx.tick(TickType::Far, ALL)?;
+
+            log!(
+                ctx.current_tick, "[{}] <{}> <{}> walk on wall {}:{} target={}/{} ball={} normal={:?}",
+                ctx.robot_id, ctx.order_id, ctx.state_id,
+                ctx.simulator.current_time(), ctx.used_path_micro_ticks,
+                ctx.simulator.me().position().distance(self.target), max_distance_to_target,
+                ctx.simulator.me().position().distance(ctx.simulator.ball().position()),
+                ctx.simulator.me().touch_normal()
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct JumpFromWall {
+}
+
+impl JumpFromWall {
+    pub fn perform<'r, 'a, G>(&self, ctx: &mut Context<'r, 'a, G>) -> Result
+        where G: Fn(i32, i32) -> Option<&'a Action> {
+
+        use crate::my_strategy::entity::Entity;
+        use crate::my_strategy::plane::Plane;
+
+        let touch_normal = match ctx.simulator.me().touch_normal() {
+            Some(v) => v,
+            None => return Err(Error::BadCondition),
+        };
+
+        #[cfg(feature = "enable_stats")]
+        {
+            ctx.stats.time_to_jump = ctx.simulator.current_time();
+        }
+
+        *ctx.simulator.me_mut().action_mut() = Action::default();
+
+        log!(
+            ctx.current_tick, "[{}] <{}> <{}> jump from wall {}:{} normal={:?}",
+            ctx.robot_id, ctx.order_id, ctx.state_id,
+            ctx.simulator.current_time(), ctx.used_path_micro_ticks, touch_normal
+        );
+
+        let to_ball = Plane::projected(ctx.simulator.ball().position() - ctx.simulator.me().position(), touch_normal);
+        let target_velocity = ctx.simulator.rules().get_target_velocity(to_ball);
+
+        ctx.simulator.me_mut().action_mut().jump_speed = ctx.simulator.rules().ROBOT_MAX_JUMP_SPEED;
+        ctx.simulator.me_mut().action_mut().set_target_velocity(target_velocity);
+
+        ctx.tick(TickType::Near, ALL)?;
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct WalkToRobot {
     pub direction: Vec3,
@@ -376,6 +580,42 @@ impl Jump {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct FollowActions {
+    pub actions: Vec<Action>,
+}
+
+impl FollowActions {
+    pub fn perform<'r, 'a, G>(&self, ctx: &mut Context<'r, 'a, G>) -> Result
+        where G: Fn(i32, i32) -> Option<&'a Action> {
+
+        use crate::my_strategy::entity::Entity;
+
+        log!(
+            ctx.current_tick, "[{}] <{}> <{}> follow {} actions {}:{}",
+            ctx.robot_id, ctx.order_id, ctx.state_id, self.actions.len(),
+            ctx.simulator.current_time(), ctx.used_path_micro_ticks
+        );
+
+        for action in self.actions.iter() {
+            let max_distance_to_ball = ctx.simulator.rules().ball_distance_limit()
+                + ctx.simulator.rules().ROBOT_MAX_GROUND_SPEED * ctx.simulator.rules().tick_time_interval();
+            let tick_type = if ctx.simulator.me().position().distance(ctx.simulator.ball().position())
+                > max_distance_to_ball {
+                TickType::Far
+            } else {
+                TickType::Near
+            };
+
+            *ctx.simulator.me_mut().action_mut() = action.clone();
+
+            ctx.tick(tick_type, ALL)?;
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct WatchBallMove {
 }
@@ -537,11 +777,118 @@ impl WatchMeJump {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct AerialIntercept {
+    pub nitro_budget: f64,
+}
+
+impl AerialIntercept {
+    pub fn perform<'r, 'a, G>(&self, ctx: &mut Context<'r, 'a, G>) -> Result
+        where G: Fn(i32, i32) -> Option<&'a Action> {
+        use crate::my_strategy::simulator::RobotCollisionType;
+        use crate::my_strategy::entity::Entity;
+
+        *ctx.simulator.me_mut().action_mut() = Action::default();
+
+        let min_nitro_amount = (ctx.simulator.me().nitro_amount() - self.nitro_budget).max(0.0);
+        let mut collided_with_ball = false;
+
+        log!(
+            ctx.current_tick, "[{}] <{}> <{}> aerial intercept {}:{} nitro_budget={} distance_to_arena={}/{}",
+            ctx.robot_id, ctx.order_id, ctx.state_id,
+            ctx.simulator.current_time(), ctx.used_path_micro_ticks, self.nitro_budget,
+            ctx.simulator.me().distance_to_arena(), ctx.simulator.me().radius()
+        );
+
+        while ctx.simulator.me().distance_to_arena() - ctx.simulator.me().radius() > 1e-3
+            && !(
+                collided_with_ball
+                && ctx.simulator.me().collision_type() == RobotCollisionType::None
+        ) {
+            if !collided_with_ball {
+                collided_with_ball = ctx.simulator.me().collision_type() != RobotCollisionType::None;
+            }
+
+            let nitro_amount = ctx.simulator.me().nitro_amount() - min_nitro_amount;
+
+            ctx.simulator.me_mut().action_mut().jump_speed = ctx.simulator.rules().ROBOT_MAX_JUMP_SPEED;
+
+            if nitro_amount > 0.0 && !collided_with_ball {
+                let (time, to_intercept) = get_aerial_intercept(nitro_amount, &ctx.simulator);
+                let target_velocity = ctx.simulator.me().velocity() + to_intercept / time;
+                ctx.simulator.me_mut().action_mut().set_target_velocity(target_velocity);
+                ctx.simulator.me_mut().action_mut().use_nitro = true;
+
+                log!(
+                    ctx.current_tick, "[{}] <{}> <{}> aerial intercept {}:{} time={} to_intercept={:?}",
+                    ctx.robot_id, ctx.order_id, ctx.state_id,
+                    ctx.simulator.current_time(), ctx.used_path_micro_ticks, time, to_intercept
+                );
+            } else {
+                ctx.simulator.me_mut().action_mut().use_nitro = false;
+            }
+
+            ctx.tick(TickType::Near, ALL)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn get_aerial_intercept(nitro_amount: f64, simulator: &Simulator) -> (f64, Vec3) {
+    use crate::my_strategy::physics::MoveEquation;
+
+    let rules = simulator.rules();
+    let time_interval = rules.tick_time_interval();
+    let my_move_equation = MoveEquation::from_robot(simulator.me().base(), rules);
+    let ball_move_equation = MoveEquation::from_solid(simulator.ball(), rules);
+    let max_ticks = (get_aerial_max_time(rules) / time_interval).ceil() as usize;
+
+    let mut best = None;
+
+    for tick in 1..=max_ticks {
+        let time = tick as f64 * time_interval;
+        let to_intercept = ball_move_equation.get_position(time) - my_move_equation.get_position(time);
+        let miss = to_intercept.norm() - rules.ball_distance_limit() - get_nitro_reach(nitro_amount, time, rules);
+        if miss <= 0.0 {
+            return (time, to_intercept);
+        }
+        match best {
+            Some((best_miss, _, _)) if best_miss <= miss => (),
+            _ => best = Some((miss, time, to_intercept)),
+        }
+    }
+
+    best.map(|(_, time, to_intercept)| (time, to_intercept)).unwrap()
+}
+
+pub fn get_aerial_max_time(rules: &Rules) -> f64 {
+    2.0 * rules.jump_to_max_height_time()
+}
+
+pub fn get_nitro_reach(nitro_amount: f64, time: f64, rules: &Rules) -> f64 {
+    use crate::my_strategy::common::Square;
+
+    let acceleration = rules.ROBOT_NITRO_ACCELERATION;
+    let nitro_time = rules.nitro_time(nitro_amount);
+
+    if time <= nitro_time {
+        acceleration * time.square() / 2.0
+    } else {
+        acceleration * nitro_time.square() / 2.0 + acceleration * nitro_time * (time - nitro_time)
+    }
+}
+
+pub fn get_aerial_reach(height: f64, nitro_amount: f64, rules: &Rules) -> Option<f64> {
+    rules.jump_height_time_with_nitro(height, nitro_amount)
+        .map(|time| rules.ROBOT_MAX_GROUND_SPEED * time + rules.ball_distance_limit())
+}
+
 #[derive(Debug, Clone)]
 pub struct Observe {
     pub number: usize,
     pub wait_until: f64,
-    pub max_z: f64,
+    pub zone: Zone,
 }
 
 impl Observe {
@@ -585,13 +932,24 @@ impl Observe {
 
             if self.number == 0 || !first {
                 if ctx.simulator.current_time() >= self.wait_until
-                    && ball_position.z() < self.max_z
+                    && self.zone.contains(ball_position)
                     && (
                         ball_position.y() < rules.max_robot_jump_height() || (
                             distance < rules.max_robot_jump_height()
                             && ball_position.y() < rules.max_robot_wall_walk_height()
                             && Vec3::j().cos(normal) >= 0.0
                         )
+                        || (
+                            ctx.config.use_wall_play
+                            && distance < rules.max_robot_jump_height()
+                            && ball_position.y() < rules.max_robot_wall_walk_height() + rules.BALL_RADIUS
+                        )
+                        || (
+                            ctx.config.use_aerial_control
+                            && get_aerial_reach(ball_position.y(), ctx.simulator.me().nitro_amount(), rules)
+                                .map(|v| ball_position.with_y(0.0).distance(ctx.simulator.me().position().with_y(0.0)) < v)
+                                .unwrap_or(false)
+                        )
                         || (
                             collided_opponent.is_some()
                             && ball_position.y() > rules.BALL_RADIUS
@@ -601,7 +959,7 @@ impl Observe {
                 }
 
                 if let Some(v) = collided_opponent {
-                    if v.position().z() < self.max_z
+                    if self.zone.contains(v.position())
                         && v.position().distance(ball_position) < rules.arena.depth / 8.0
                         && v.position().distance(ctx.simulator.me().position()) < rules.arena.depth / 8.0 {
                         return Err(Error::PushRobot(v.id()));
@@ -15,19 +15,44 @@ mod strategy;
 #[allow(dead_code)]
 mod examples;
 
+#[allow(dead_code)]
+mod training;
+
 use crate::my_strategy::world::World;
 use crate::my_strategy::vec3::Vec3;
 use crate::my_strategy::random::XorShiftRng;
 use crate::my_strategy::simulator::Simulator;
 use crate::my_strategy::my_strategy_impl::MyStrategyImpl;
+use crate::training::Sample;
+use crate::my_strategy::kickoff::KickoffPlan;
+use crate::model::Action;
 
 const DURATION: i32 = 150;
 const SIMULATIONS: usize = 1000;
+const SELF_PLAY_GAMES: usize = 100;
+const SELF_PLAY_MAX_TICKS: i32 = 1000;
+const SELF_PLAY_SAMPLE_INTERVAL: i32 = 10;
+const FIT_EPOCHS: usize = 100;
+const FIT_LEARNING_RATE: f64 = 0.01;
+#[cfg(feature = "enable_trace")]
+const TRACE_ALTERNATIVES: usize = 5;
+const KICKOFF_VARIATIONS: usize = 20;
+const KICKOFF_MAX_TICKS: i32 = 100;
+const KICKOFF_POSITION_JITTER: f64 = 5.0;
+const REACHABILITY_MICRO_TICKS_PER_TICK: usize = 10;
+const REACHABILITY_MAX_TICKS: i32 = 300;
 
 fn main() {
     match std::env::args().nth(1).unwrap().as_str() {
         "generate_empty" => generate_empty(),
         "check_goalkeeper" => check_goalkeeper(),
+        "generate_positions" => generate_positions(),
+        "fit_model" => fit_model(),
+        #[cfg(feature = "enable_trace")]
+        "view_trace" => view_trace(),
+        "fit_opponent_model" => fit_opponent_model(),
+        "generate_kickoff_playbook" => generate_kickoff_playbook(),
+        "generate_reachability_table" => generate_reachability_table(),
         _ => unimplemented!(),
     }
 }
@@ -114,6 +139,378 @@ fn generate_empty() {
     }
 }
 
+fn generate_positions() {
+    use crate::my_strategy::random::SeedableRng;
+
+    let games: usize = std::env::args().nth(2).map(|v| v.parse().unwrap()).unwrap_or(SELF_PLAY_GAMES);
+
+    eprintln!("games: {}", games);
+
+    let seed: u32 = std::env::args().nth(3).map(|v| v.parse().unwrap()).unwrap_or(4170596740);
+
+    eprintln!("seed: {}", seed);
+
+    let world = examples::example_world(examples::GameType::TwoRobots);
+
+    let bounds: Bounds = default_bounds(&world);
+
+    let mut rng = XorShiftRng::from_seed([seed, 943075939, 3311701793, 474463886]);
+
+    let mut goals = 0;
+
+    for _ in 0..games {
+        let parameters = bounds.generate(&mut rng);
+
+        let (score, samples) = simulate_self_play(
+            parameters.ball_position,
+            parameters.ball_velocity * parameters.speed,
+            world.clone()
+        );
+
+        if score == 0 {
+            continue;
+        }
+
+        goals += 1;
+
+        let label = if score > 0 { 1.0 } else { 0.0 };
+
+        for features in samples {
+            println!("{}", serde_json::to_string(&Sample { features, label }).unwrap());
+        }
+    }
+
+    eprintln!("goals: {}", goals);
+}
+
+fn simulate_self_play(ball_position: Vec3, ball_velocity: Vec3, mut world: World) -> (i32, Vec<Vec<f64>>) {
+    use crate::examples::example_rng;
+    use crate::my_strategy::learning::get_features;
+
+    world.game.ball.set_position(ball_position);
+    world.game.ball.set_velocity(ball_velocity);
+
+    let mut rng = example_rng(&world.rules);
+    let mut simulator = Simulator::new(&world, world.me.id);
+    let opponent = world.game.robots.iter()
+        .find(|v| !v.is_teammate)
+        .unwrap()
+        .opposite();
+    let mut my_strategy = MyStrategyImpl::new(
+        world.config.clone(),
+        &world.me,
+        &world.rules,
+        &world.game,
+    );
+    let mut opponent_strategy = MyStrategyImpl::new(
+        world.config.clone(),
+        &opponent,
+        &world.rules,
+        &world.game.opposite(),
+    );
+    let mut samples = Vec::new();
+
+    while simulator.score() == 0 && simulator.current_tick() < SELF_PLAY_MAX_TICKS {
+        if simulator.current_tick() % SELF_PLAY_SAMPLE_INTERVAL == 0 {
+            samples.push(get_features(&simulator));
+        }
+        let actions = get_self_play_actions(&mut my_strategy, &mut opponent_strategy, &simulator);
+        for (robot, action) in simulator.robots_mut().iter_mut().zip(actions.into_iter()) {
+            *robot.action_mut() = action;
+        }
+        simulator.tick(
+            simulator.rules().tick_time_interval(),
+            simulator.rules().MICROTICKS_PER_TICK,
+            &mut rng,
+        );
+    }
+
+    (simulator.score(), samples)
+}
+
+fn get_self_play_actions(my_strategy: &mut MyStrategyImpl, opponent_strategy: &mut MyStrategyImpl,
+                         simulator: &Simulator) -> Vec<Action> {
+    use crate::strategy::Strategy;
+
+    let game = simulator.game();
+    let opposite_game = game.opposite();
+
+    game.robots.iter()
+        .map(|robot| {
+            let mut action = Action::default();
+            if robot.is_teammate {
+                my_strategy.act(robot, simulator.rules(), &game, &mut action);
+                action
+            } else {
+                opponent_strategy.act(&robot.opposite(), simulator.rules(), &opposite_game, &mut action);
+                action.opposite()
+            }
+        })
+        .collect()
+}
+
+fn generate_kickoff_playbook() {
+    use crate::my_strategy::random::{Rng, SeedableRng};
+
+    let variations: usize = std::env::args().nth(2).map(|v| v.parse().unwrap()).unwrap_or(KICKOFF_VARIATIONS);
+
+    eprintln!("variations: {}", variations);
+
+    let seed: u32 = std::env::args().nth(3).map(|v| v.parse().unwrap()).unwrap_or(4170596740);
+
+    eprintln!("seed: {}", seed);
+
+    let mut rng = XorShiftRng::from_seed([seed, 943075939, 3311701793, 474463886]);
+    let mut playbook = Vec::new();
+
+    for &game_type in [examples::GameType::TwoRobots, examples::GameType::TwoRobotsWithNitro,
+                       examples::GameType::ThreeRobotsWithNitro].iter() {
+        let world = examples::example_world(game_type);
+        let mut plans = 0;
+
+        for _ in 0..variations {
+            let mut game = world.game.clone();
+            for robot in game.robots.iter_mut() {
+                robot.x = (robot.x + rng.gen_range(-KICKOFF_POSITION_JITTER, KICKOFF_POSITION_JITTER))
+                    .max(-world.rules.arena.width / 2.0 + world.rules.arena.corner_radius)
+                    .min(world.rules.arena.width / 2.0 - world.rules.arena.corner_radius);
+            }
+            let me = game.robots.iter().find(|v| v.id == world.me.id).unwrap().clone();
+            let world = World::new(world.config.clone(), me, world.rules.clone(), game);
+
+            if let Some(plan) = simulate_kickoff(world) {
+                playbook.push(plan);
+                plans += 1;
+            }
+        }
+
+        eprintln!("{:?}: {} plans", game_type, plans);
+    }
+
+    println!("{}", serde_json::to_string(&playbook).unwrap());
+}
+
+fn simulate_kickoff(world: World) -> Option<KickoffPlan> {
+    use crate::examples::example_rng;
+    use crate::my_strategy::entity::Entity;
+    use crate::my_strategy::kickoff::KickoffRobotPlan;
+
+    let mut rng = example_rng(&world.rules);
+    let mut simulator = Simulator::new(&world, world.me.id);
+    let opponent = world.game.robots.iter()
+        .find(|v| !v.is_teammate)
+        .unwrap()
+        .opposite();
+    let mut my_strategy = MyStrategyImpl::new(
+        world.config.clone(),
+        &world.me,
+        &world.rules,
+        &world.game,
+    );
+    let mut opponent_strategy = MyStrategyImpl::new(
+        world.config.clone(),
+        &opponent,
+        &world.rules,
+        &world.game.opposite(),
+    );
+    let mut robots: Vec<(i32, KickoffRobotPlan)> = world.game.robots.iter()
+        .filter(|v| v.is_teammate)
+        .map(|v| (v.id, KickoffRobotPlan { position: v.position(), actions: Vec::new() }))
+        .collect();
+
+    while simulator.ball().velocity().with_y(0.0).norm() == 0.0 && simulator.current_tick() < KICKOFF_MAX_TICKS {
+        let actions = get_self_play_actions(&mut my_strategy, &mut opponent_strategy, &simulator);
+        for (robot, action) in simulator.robots().iter().zip(actions.iter()).filter(|(v, _)| v.is_teammate()) {
+            robots.iter_mut()
+                .find(|(id, _)| *id == robot.id())
+                .unwrap()
+                .1.actions.push(action.clone());
+        }
+        for (robot, action) in simulator.robots_mut().iter_mut().zip(actions.into_iter()) {
+            *robot.action_mut() = action;
+        }
+        simulator.tick(
+            simulator.rules().tick_time_interval(),
+            simulator.rules().MICROTICKS_PER_TICK,
+            &mut rng,
+        );
+    }
+
+    if simulator.ball().velocity().z() <= 0.0 {
+        return None;
+    }
+
+    Some(KickoffPlan {
+        team_size: world.rules.team_size,
+        nitro: !world.game.nitro_packs.is_empty(),
+        opponents: world.game.robots.iter()
+            .filter(|v| !v.is_teammate)
+            .map(|v| v.position())
+            .collect(),
+        robots: robots.into_iter().map(|(_, v)| v).collect(),
+    })
+}
+
+fn fit_model() {
+    use std::io::BufRead;
+    use crate::my_strategy::random::SeedableRng;
+    use crate::my_strategy::learning::{Model, FEATURES_NUMBER};
+
+    let hidden: Vec<usize> = std::env::args().nth(2)
+        .map(|v| v.split(',').filter(|v| !v.is_empty()).map(|v| v.parse().unwrap()).collect())
+        .unwrap_or_default();
+
+    eprintln!("hidden: {:?}", hidden);
+
+    let epochs: usize = std::env::args().nth(3).map(|v| v.parse().unwrap()).unwrap_or(FIT_EPOCHS);
+
+    eprintln!("epochs: {}", epochs);
+
+    let stdin = std::io::stdin();
+    let mut samples: Vec<Sample> = stdin.lock().lines()
+        .map(|line| serde_json::from_str(&line.unwrap()).unwrap())
+        .collect();
+
+    eprintln!("samples: {}", samples.len());
+
+    let mut rng = XorShiftRng::from_seed([2960285387, 943075939, 3311701793, 474463886]);
+    let mut model = Model::new(FEATURES_NUMBER, &hidden, &mut rng);
+
+    eprintln!("initial loss: {}", model.loss(&samples));
+
+    model.fit(&mut samples, epochs, FIT_LEARNING_RATE, &mut rng);
+
+    eprintln!("final loss: {}", model.loss(&samples));
+
+    println!("{}", serde_json::to_string(&model).unwrap());
+}
+
+#[cfg(feature = "enable_trace")]
+fn view_trace() {
+    use std::io::BufRead;
+    use crate::my_strategy::trace::Trace;
+
+    let order_id: Option<i32> = std::env::args().nth(2).map(|v| v.parse().unwrap());
+    let alternatives: usize = std::env::args().nth(3).map(|v| v.parse().unwrap()).unwrap_or(TRACE_ALTERNATIVES);
+
+    let stdin = std::io::stdin();
+    for line in stdin.lock().lines() {
+        let trace: Trace = match serde_json::from_str(&line.unwrap()) {
+            Ok(v) => v,
+            Err(_) => continue,
+        };
+
+        if order_id.is_some() && order_id != Some(trace.order_id) {
+            continue;
+        }
+
+        println!(
+            "tick={} robot={} order={} states={} final={:?}",
+            trace.current_tick, trace.robot_id, trace.order_id, trace.nodes.len(), trace.final_state_id
+        );
+
+        for node in trace.get_top_alternatives(alternatives) {
+            let path = trace.get_path(node.id).iter()
+                .filter_map(|v| v.transition.clone())
+                .collect::<Vec<_>>()
+                .join(" -> ");
+            let mark = if Some(node.id) == trace.final_state_id { "*" } else { " " };
+            println!(
+                "{} <{}> score={} time={} state={} error={:?} path={}",
+                mark, node.id, node.score, node.time, node.state, node.error, path
+            );
+        }
+    }
+}
+
+fn fit_opponent_model() {
+    use std::io::BufRead;
+    use crate::my_strategy::random::SeedableRng;
+    use crate::training::{Observation, RobotObservation, fit_opponent_model_by_observations};
+
+    let player_index: usize = std::env::args().nth(2).unwrap().parse().unwrap();
+
+    eprintln!("player_index: {}", player_index);
+
+    let epochs: usize = std::env::args().nth(3).map(|v| v.parse().unwrap()).unwrap_or(FIT_EPOCHS);
+
+    eprintln!("epochs: {}", epochs);
+
+    let world = examples::example_world(examples::GameType::TwoRobots);
+    let opposite = |v: Vec3| if player_index == 1 { v.opposite() } else { v };
+
+    let stdin = std::io::stdin();
+    let observations: Vec<Observation> = stdin.lock().lines()
+        .filter_map(|line| serde_json::from_str::<ReplayRecord>(&line.unwrap()).ok())
+        .map(|record| Observation {
+            current_tick: record.current_tick,
+            ball_position: opposite(record.ball.position),
+            robots: record.robots.iter()
+                .filter(|v| v.player_index == player_index)
+                .map(|v| RobotObservation {
+                    id: v.id,
+                    position: opposite(v.position),
+                    velocity: opposite(v.velocity),
+                })
+                .collect(),
+        })
+        .collect();
+
+    eprintln!("observations: {}", observations.len());
+
+    let mut rng = XorShiftRng::from_seed([2960285387, 943075939, 3311701793, 474463886]);
+    let model = fit_opponent_model_by_observations(&observations, &world.rules, epochs, FIT_LEARNING_RATE, &mut rng);
+
+    println!("{}", serde_json::to_string(&model).unwrap());
+}
+
+fn generate_reachability_table() {
+    use crate::my_strategy::reachability::ReachabilityTable;
+    use crate::training::Grid;
+
+    let step: f64 = std::env::args().nth(2).map(|v| v.parse().unwrap()).unwrap_or(2.0);
+
+    eprintln!("step: {}", step);
+
+    let world = examples::example_world(examples::GameType::OneRobotWithNitro);
+    let rules = &world.rules;
+    let range = |min: f64, max: f64, step: f64| {
+        (0..=((max - min) / step).round() as usize)
+            .map(|v| min + v as f64 * step)
+            .collect::<Vec<_>>()
+    };
+    let grid = Grid {
+        speeds: range(0.0, rules.ROBOT_MAX_GROUND_SPEED, rules.ROBOT_MAX_GROUND_SPEED / 6.0),
+        forward: range(-rules.arena.depth / 2.0 + rules.arena.bottom_radius, rules.arena.depth / 2.0 - rules.arena.bottom_radius, step),
+        lateral: range(0.0, rules.arena.width / 2.0 - rules.arena.bottom_radius, step),
+        heights: range(0.0, rules.max_robot_jump_height(), 0.25),
+        micro_ticks_per_tick: REACHABILITY_MICRO_TICKS_PER_TICK,
+        max_ticks: REACHABILITY_MAX_TICKS,
+    };
+
+    println!("{}", serde_json::to_string(&ReachabilityTable::generate(&world, &grid)).unwrap());
+}
+
+#[derive(Deserialize)]
+struct ReplayRecord {
+    current_tick: i32,
+    robots: Vec<ReplayRobot>,
+    ball: ReplayBall,
+}
+
+#[derive(Deserialize)]
+struct ReplayRobot {
+    id: i32,
+    player_index: usize,
+    position: Vec3,
+    velocity: Vec3,
+}
+
+#[derive(Deserialize)]
+struct ReplayBall {
+    position: Vec3,
+}
+
 fn default_bounds(world: &World) -> Bounds {
     Bounds {
         ball_position: Vec3Bounds {
@@ -227,7 +624,6 @@ fn simulate_empty(ball_position: Vec3, ball_velocity: Vec3, mut world: World) ->
 pub fn simulate_while<P>(mut my_strategy: Option<&mut MyStrategyImpl>, simulator: &mut Simulator,
                          rng: &mut XorShiftRng, predicate: P)
     where P: Fn(&mut Simulator) -> bool {
-    use crate::model::Action;
     use crate::strategy::Strategy;
 
     while predicate(simulator) {
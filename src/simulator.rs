@@ -6,6 +6,7 @@ use crate::my_strategy::world::World;
 use crate::my_strategy::entity::Entity;
 use crate::my_strategy::arena::ArenaCollisionMask;
 use crate::my_strategy::solid::{Solid, SolidId};
+use crate::my_strategy::ball_predictor::BallPredictor;
 
 #[cfg(feature = "enable_render")]
 use crate::my_strategy::render::{Render, Color};
@@ -222,7 +223,7 @@ impl Entity for RobotExt {
     }
 }
 
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum BallCollisionType {
     None,
     Arena,
@@ -527,6 +528,14 @@ impl Simulator {
         }
     }
 
+    pub fn with_me(mut self, robot_id: i32) -> Self {
+        for robot in self.robots.iter_mut() {
+            robot.is_me = robot.id() == robot_id;
+        }
+        self.me_index = self.robots.iter().position(|v| v.is_me).unwrap();
+        self
+    }
+
     pub fn robots(&self) -> &Vec<RobotExt> {
         &self.robots
     }
@@ -539,6 +548,10 @@ impl Simulator {
         &self.ball
     }
 
+    pub fn ball_mut(&mut self) -> &mut BallExt {
+        &mut self.ball
+    }
+
     pub fn rules(&self) -> &Rules {
         &self.rules
     }
@@ -622,6 +635,25 @@ impl Simulator {
         for _ in 0..micro_ticks_per_tick {
             self.micro_tick(micro_tick_time_interval, rng);
         }
+        self.finish_tick(time_interval);
+    }
+
+    pub fn tick_predicted_ball(&mut self, time_interval: f64, ball_predictor: &BallPredictor) {
+        let state = *ball_predictor.get(self.current_tick + 1).unwrap();
+        for robot in self.robots.iter_mut() {
+            robot.collision_type = RobotCollisionType::None;
+        }
+        self.ball.collision_type = state.collision_type;
+        self.ball.set_position(state.position);
+        self.ball.set_velocity(state.velocity);
+        if self.score == 0 {
+            self.score = state.score;
+        }
+        self.current_micro_tick += ball_predictor.micro_ticks_per_tick() as i32;
+        self.finish_tick(time_interval);
+    }
+
+    fn finish_tick(&mut self, time_interval: f64) {
         for nitro_pack in self.nitro_packs.iter_mut() {
             nitro_pack.respawn_ticks = if let Some(v) = nitro_pack.respawn_ticks {
                 if v > 1 {
@@ -0,0 +1,50 @@
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Trace {
+    pub player_id: i32,
+    pub robot_id: i32,
+    pub current_tick: i32,
+    pub order_id: i32,
+    pub final_state_id: Option<i32>,
+    pub nodes: Vec<Node>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Node {
+    pub id: i32,
+    pub parent_id: Option<i32>,
+    pub transition: Option<String>,
+    pub state: String,
+    pub score: i32,
+    pub time: f64,
+    pub error: Option<String>,
+}
+
+impl Trace {
+    pub fn get_node(&self, id: i32) -> Option<&Node> {
+        self.nodes.iter().find(|v| v.id == id)
+    }
+
+    pub fn get_path(&self, id: i32) -> Vec<&Node> {
+        let mut result = Vec::new();
+        let mut current = self.get_node(id);
+        while let Some(node) = current {
+            result.push(node);
+            current = node.parent_id.and_then(|v| self.get_node(v));
+        }
+        result.reverse();
+        result
+    }
+
+    pub fn get_leaves(&self) -> Vec<&Node> {
+        self.nodes.iter()
+            .filter(|node| !self.nodes.iter().any(|v| v.parent_id == Some(node.id)))
+            .collect()
+    }
+
+    pub fn get_top_alternatives(&self, number: usize) -> Vec<&Node> {
+        let mut leaves = self.get_leaves();
+        leaves.sort_by_key(|v| (-v.score, v.id));
+        leaves.truncate(number);
+        leaves
+    }
+}
@@ -0,0 +1,288 @@
+use crate::model::Rules;
+use crate::my_strategy::vec3::Vec3;
+use crate::my_strategy::random::XorShiftRng;
+use crate::my_strategy::learning::{Activation, Layer, Model};
+use crate::my_strategy::opponent_model::OpponentModel;
+use crate::my_strategy::reachability::ReachabilityTable;
+use crate::my_strategy::world::World;
+use crate::my_strategy::simulator::Simulator;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Sample {
+    pub features: Vec<f64>,
+    pub label: f64,
+}
+
+impl Model {
+    pub fn new(inputs: usize, hidden: &[usize], rng: &mut XorShiftRng) -> Self {
+        use crate::my_strategy::random::Rng;
+
+        let mut layers = Vec::new();
+        let mut size = inputs;
+        for (i, &outputs) in hidden.iter().chain(std::iter::once(&1)).enumerate() {
+            let bound = 1.0 / (size as f64).sqrt();
+            layers.push(Layer {
+                weights: (0..outputs)
+                    .map(|_| (0..size).map(|_| rng.gen_range(-bound, bound)).collect())
+                    .collect(),
+                biases: vec![0.0; outputs],
+                activation: if i == hidden.len() {
+                    Activation::Sigmoid
+                } else {
+                    Activation::Relu
+                },
+            });
+            size = outputs;
+        }
+        Model { layers }
+    }
+
+    pub fn loss(&self, samples: &[Sample]) -> f64 {
+        let eps = 1e-12;
+        samples.iter()
+            .map(|sample| {
+                let p = self.predict(&sample.features).max(eps).min(1.0 - eps);
+                -(sample.label * p.ln() + (1.0 - sample.label) * (1.0 - p).ln())
+            })
+            .sum::<f64>() / samples.len().max(1) as f64
+    }
+
+    pub fn fit(&mut self, samples: &mut [Sample], epochs: usize, learning_rate: f64, rng: &mut XorShiftRng) {
+        use crate::my_strategy::random::Rng;
+
+        for _ in 0..epochs {
+            rng.shuffle(samples);
+            for sample in samples.iter() {
+                self.fit_sample(sample, learning_rate);
+            }
+        }
+    }
+
+    fn fit_sample(&mut self, sample: &Sample, learning_rate: f64) {
+        let mut outputs = vec![sample.features.clone()];
+        for layer in self.layers.iter() {
+            let output = layer.apply(outputs.last().unwrap());
+            outputs.push(output);
+        }
+
+        // Log loss gradient with sigmoid output is just prediction error.
+        let mut deltas = vec![outputs.last().unwrap()[0] - sample.label];
+        let activations: Vec<Activation> = self.layers.iter().map(|v| v.activation).collect();
+
+        for (i, layer) in self.layers.iter_mut().enumerate().rev() {
+            let input = &outputs[i];
+            let previous = if i > 0 {
+                let activation = activations[i - 1];
+                (0..input.len())
+                    .map(|j| {
+                        let sum = layer.weights.iter().zip(deltas.iter()).map(|(w, d)| w[j] * d).sum::<f64>();
+                        sum * derivative(activation, input[j])
+                    })
+                    .collect()
+            } else {
+                Vec::new()
+            };
+            for (k, delta) in deltas.iter().enumerate() {
+                for (j, x) in input.iter().enumerate() {
+                    layer.weights[k][j] -= learning_rate * delta * x;
+                }
+                layer.biases[k] -= learning_rate * delta;
+            }
+            deltas = previous;
+        }
+    }
+}
+
+fn derivative(activation: Activation, output: f64) -> f64 {
+    match activation {
+        Activation::Identity => 1.0,
+        Activation::Relu => if output > 0.0 { 1.0 } else { 0.0 },
+        Activation::Sigmoid => output * (1.0 - output),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Observation {
+    pub current_tick: i32,
+    pub ball_position: Vec3,
+    pub robots: Vec<RobotObservation>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RobotObservation {
+    pub id: i32,
+    pub position: Vec3,
+    pub velocity: Vec3,
+}
+
+pub fn fit_opponent_model_by_observations(observations: &[Observation], rules: &Rules, epochs: usize,
+                                          learning_rate: f64, rng: &mut XorShiftRng) -> OpponentModel {
+    use crate::my_strategy::opponent_model::{FEATURES_NUMBER, get_features};
+
+    let ground_y = rules.ROBOT_RADIUS + 0.1;
+    let mut samples = Vec::new();
+    let mut speeds = Vec::new();
+    let mut jump_distances = Vec::new();
+
+    for pair in observations.windows(2) {
+        let (before, after) = (&pair[0], &pair[1]);
+        if after.current_tick != before.current_tick + 1 {
+            continue;
+        }
+        for robot in before.robots.iter() {
+            let next = match after.robots.iter().find(|v| v.id == robot.id) {
+                Some(v) => v,
+                None => continue,
+            };
+            if robot.position.y() > ground_y {
+                continue;
+            }
+            let distance_to_ball = robot.position.distance(before.ball_position);
+            if next.velocity.y() - robot.velocity.y() > rules.ROBOT_MAX_JUMP_SPEED / 2.0 {
+                jump_distances.push(distance_to_ball);
+                continue;
+            }
+            let velocity = next.velocity.with_y(0.0);
+            if velocity.norm() < 1.0 {
+                continue;
+            }
+            speeds.push(velocity.norm() / rules.ROBOT_MAX_GROUND_SPEED);
+            let to_ball = (before.ball_position - robot.position).with_y(0.0);
+            let to_goal = (rules.get_my_goal_target() - robot.position).with_y(0.0);
+            let label = if velocity.cos(to_ball) > velocity.cos(to_goal) { 1.0 } else { 0.0 };
+            let is_nearest = before.robots.iter()
+                .all(|v| v.position.distance(before.ball_position) >= distance_to_ball);
+            samples.push(Sample {
+                features: get_features(rules, before.ball_position, robot.position, is_nearest),
+                label,
+            });
+        }
+    }
+
+    let mut intercept = Model::new(FEATURES_NUMBER, &[], rng);
+    intercept.fit(&mut samples, epochs, learning_rate, rng);
+
+    OpponentModel {
+        intercept,
+        speed_factor: mean(&speeds).unwrap_or(1.0).min(1.0),
+        jump_distance: mean(&jump_distances).unwrap_or(rules.ball_distance_limit()),
+    }
+}
+
+fn mean(values: &[f64]) -> Option<f64> {
+    if values.is_empty() {
+        None
+    } else {
+        Some(values.iter().sum::<f64>() / values.len() as f64)
+    }
+}
+
+pub struct Grid {
+    pub speeds: Vec<f64>,
+    pub forward: Vec<f64>,
+    pub lateral: Vec<f64>,
+    pub heights: Vec<f64>,
+    pub micro_ticks_per_tick: usize,
+    pub max_ticks: i32,
+}
+
+impl ReachabilityTable {
+    pub fn generate(world: &World, grid: &Grid) -> Self {
+        let mut ground_times = Vec::with_capacity(grid.speeds.len() * grid.forward.len() * grid.lateral.len());
+
+        for &speed in grid.speeds.iter() {
+            for &forward in grid.forward.iter() {
+                for &lateral in grid.lateral.iter() {
+                    ground_times.push(simulate_walk(world, grid, speed, Vec3::new(lateral, 0.0, forward)));
+                }
+            }
+        }
+
+        let heights = simulate_jump(world, grid);
+        let jump_times = grid.heights.iter()
+            .map(|height| {
+                heights.iter()
+                    .find(|(_, v)| v >= height)
+                    .map(|(time, _)| *time)
+                    .unwrap_or(f64::MAX)
+            })
+            .collect();
+
+        ReachabilityTable {
+            speeds: grid.speeds.clone(),
+            forward: grid.forward.clone(),
+            lateral: grid.lateral.clone(),
+            heights: grid.heights.clone(),
+            ground_times,
+            jump_times,
+            max_ground_speed: world.rules.ROBOT_MAX_GROUND_SPEED,
+        }
+    }
+}
+
+fn make_simulator(world: &World) -> Simulator {
+    use crate::my_strategy::entity::Entity;
+
+    let mut world = world.clone();
+    world.game.ball.set_position(Vec3::new(
+        -world.rules.arena.width / 2.0 + world.rules.BALL_RADIUS,
+        world.rules.BALL_RADIUS,
+        0.0
+    ));
+    world.game.ball.set_velocity(Vec3::default());
+
+    let mut simulator = Simulator::new(&world, world.me.id);
+    let rules = simulator.rules().clone();
+
+    simulator.robots_mut().iter_mut()
+        .for_each(|v| v.set_ignore(!v.is_me()));
+    simulator.me_mut().set_position(Vec3::only_y(rules.ROBOT_RADIUS));
+    simulator.me_mut().set_velocity(Vec3::default());
+    simulator.me_mut().set_nitro_amount(0.0);
+    simulator
+}
+
+fn simulate_walk(world: &World, grid: &Grid, speed: f64, target: Vec3) -> f64 {
+    use crate::my_strategy::random::{XorShiftRng, SeedableRng};
+    use crate::my_strategy::entity::Entity;
+
+    let mut rng = XorShiftRng::from_seed([2960285387, 943075939, 3311701793, 474463886]);
+    let mut simulator = make_simulator(world);
+    let rules = simulator.rules().clone();
+    let target = target.with_y(rules.ROBOT_RADIUS);
+
+    simulator.me_mut().set_velocity(Vec3::only_z(speed));
+
+    while simulator.current_tick() < grid.max_ticks {
+        let to_target = (target - simulator.me().position()).with_y(0.0);
+        if to_target.norm() < rules.ROBOT_RADIUS {
+            break;
+        }
+        simulator.me_mut().action_mut().set_target_velocity(to_target.normalized() * rules.ROBOT_MAX_GROUND_SPEED);
+        simulator.tick(rules.tick_time_interval(), grid.micro_ticks_per_tick, &mut rng);
+    }
+
+    simulator.current_time()
+}
+
+fn simulate_jump(world: &World, grid: &Grid) -> Vec<(f64, f64)> {
+    use crate::my_strategy::random::{XorShiftRng, SeedableRng};
+    use crate::my_strategy::entity::Entity;
+
+    let mut rng = XorShiftRng::from_seed([2960285387, 943075939, 3311701793, 474463886]);
+    let mut simulator = make_simulator(world);
+    let rules = simulator.rules().clone();
+    let mut result = vec![(0.0, 0.0)];
+
+    simulator.me_mut().action_mut().jump_speed = rules.ROBOT_MAX_JUMP_SPEED;
+
+    while simulator.current_tick() < grid.max_ticks {
+        simulator.tick(rules.tick_time_interval(), grid.micro_ticks_per_tick, &mut rng);
+        result.push((simulator.current_time(), simulator.me().position().y() - rules.ROBOT_RADIUS));
+        if simulator.me().velocity().y() <= 0.0 {
+            break;
+        }
+    }
+
+    result
+}
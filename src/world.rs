@@ -1,5 +1,8 @@
+use std::rc::Rc;
 use crate::model::{Robot, Game, Rules};
 use crate::my_strategy::config::Config;
+use crate::my_strategy::ball_predictor::BallPredictor;
+use crate::my_strategy::opponent_profile::OpponentProfile;
 
 #[derive(Debug, Clone)]
 pub struct World {
@@ -7,15 +10,20 @@ pub struct World {
     pub me: Robot,
     pub rules: Rules,
     pub game: Game,
+    pub ball_predictor: Option<Rc<BallPredictor>>,
+    pub opponent_profile: Option<Rc<OpponentProfile>>,
     reset_ticks_left: usize,
+    opposite: bool,
 }
 
 impl World {
     pub fn new(config: Config, me: Robot, rules: Rules, game: Game) -> Self {
-        World { config, me, rules, game, reset_ticks_left: 0 }
+        let mut result = World { config, me, rules, game, ball_predictor: None, opponent_profile: None, reset_ticks_left: 0, opposite: false };
+        result.update_ball_predictor();
+        result
     }
 
-    pub fn update(&mut self, me: &Robot, game: &Game) {
+    pub fn update(&mut self, me: &Robot, game: &Game) -> usize {
         let prev_score = self.game.players.iter().map(|v| v.score).sum::<i32>();
         let curr_score = game.players.iter().map(|v| v.score).sum::<i32>();
         if prev_score < curr_score {
@@ -24,6 +32,25 @@ impl World {
         self.me = me.clone();
         self.game = game.clone();
         self.reset_ticks_left = if self.reset_ticks_left > 0 { self.reset_ticks_left - 1 } else { 0 };
+        self.update_ball_predictor()
+    }
+
+    pub fn update_ball_predictor(&mut self) -> usize {
+        if !self.config.use_ball_predictor {
+            self.ball_predictor = None;
+            return 0;
+        }
+
+        let previous = self.ball_predictor.take();
+        let advanced = previous.as_ref().and_then(|v| v.advance(self).map(|result| (v.tick(), result)));
+
+        if let Some((tick, ball_predictor)) = advanced {
+            self.ball_predictor = Some(Rc::new(ball_predictor));
+            (self.game.current_tick - tick) as usize * self.config.near_micro_ticks_per_tick
+        } else {
+            self.ball_predictor = Some(Rc::new(BallPredictor::new(self)));
+            self.config.max_ticks as usize * self.config.near_micro_ticks_per_tick
+        }
     }
 
     pub fn is_reset_ticks(&self) -> bool {
@@ -50,10 +77,17 @@ impl World {
             me: self.me.opposite(),
             rules: self.rules.clone(),
             game: self.game.opposite(),
+            ball_predictor: self.ball_predictor.as_ref().map(|v| Rc::new(v.opposite())),
+            opponent_profile: self.opponent_profile.clone(),
             reset_ticks_left: self.reset_ticks_left,
+            opposite: !self.opposite,
         }
     }
 
+    pub fn is_opposite(&self) -> bool {
+        self.opposite
+    }
+
     pub fn is_teammate(&self, robot_id: i32) -> bool {
         self.get_robot(robot_id).is_teammate
     }
@@ -0,0 +1,42 @@
+use crate::my_strategy::vec2::Vec2;
+use crate::my_strategy::vec3::Vec3;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Zone {
+    max_z: f64,
+    extension: Option<Extension>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Extension {
+    pub center: Vec2,
+    pub radius: f64,
+}
+
+impl Zone {
+    pub fn new(max_z: f64) -> Self {
+        Zone { max_z, extension: None }
+    }
+
+    pub fn with_extension(mut self, center: Vec2, radius: f64) -> Self {
+        self.extension = Some(Extension { center, radius });
+        self
+    }
+
+    pub fn max_z(&self) -> f64 {
+        self.max_z
+    }
+
+    pub fn contains(&self, position: Vec3) -> bool {
+        position.z() < self.max_z
+            || self.extension
+                .map(|v| (position.xz() - v.center).norm() < v.radius)
+                .unwrap_or(false)
+    }
+}
+
+impl From<f64> for Zone {
+    fn from(max_z: f64) -> Self {
+        Zone::new(max_z)
+    }
+}
@@ -0,0 +1,114 @@
+#[test]
+fn test_find_max_assignment_empty() {
+    use my_strategy::my_strategy::assignment::find_max_assignment;
+
+    assert_eq!(find_max_assignment(&[]), None);
+}
+
+#[test]
+fn test_find_max_assignment() {
+    use my_strategy::my_strategy::assignment::find_max_assignment;
+
+    let scores = vec![
+        vec![7, 5, 1],
+        vec![8, 2, 3],
+        vec![4, 6, 9],
+    ];
+
+    assert_eq!(find_max_assignment(&scores), Some((22, vec![1, 0, 2])));
+}
+
+#[test]
+fn test_get_optimal_roles_with_role_change_gap() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::roles::{Role, RoleType, get_optimal_roles};
+    use my_strategy::my_strategy::vec3::Vec3;
+
+    let mut world = example_world(GameType::TwoRobots);
+    let mut robots_ids: Vec<i32> = world.game.robots.iter().filter(|v| v.is_teammate).map(|v| v.id).collect();
+    robots_ids.sort();
+    let role_types = vec![RoleType::Goalkeeper, RoleType::Forward];
+
+    let roles = get_optimal_roles(&robots_ids, &role_types, &[], &world, 0);
+
+    assert_eq!(roles, vec![Role::goalkeeper(1), Role::forward(2)]);
+
+    world.game.robots.iter_mut()
+        .find(|v| v.id == 1)
+        .map(|v| v.set_position(Vec3::new(0.0, 1.0, 5.0)));
+    world.game.robots.iter_mut()
+        .find(|v| v.id == 2)
+        .map(|v| v.set_position(Vec3::new(0.0, 1.0, -25.0)));
+
+    assert_eq!(
+        get_optimal_roles(&robots_ids, &role_types, &roles, &world, 0),
+        vec![Role::forward(1), Role::goalkeeper(2)]
+    );
+    assert_eq!(get_optimal_roles(&robots_ids, &role_types, &roles, &world, 1000), roles);
+}
+
+#[test]
+fn test_get_optimal_roles_applies_role_change_gap_to_total_score() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::roles::{Role, RoleType, get_optimal_roles};
+    use my_strategy::my_strategy::vec3::Vec3;
+
+    let mut world = example_world(GameType::TwoRobots);
+    let robots_ids = vec![1, 2];
+    let role_types = vec![RoleType::Goalkeeper, RoleType::Forward];
+    let roles = vec![Role::goalkeeper(1), Role::forward(2)];
+
+    world.game.robots.iter_mut()
+        .find(|v| v.id == 1)
+        .into_iter()
+        .for_each(|v| v.set_position(Vec3::new(0.0, 1.0, 5.0)));
+    world.game.robots.iter_mut()
+        .find(|v| v.id == 2)
+        .into_iter()
+        .for_each(|v| v.set_position(Vec3::new(0.0, 1.0, -25.0)));
+
+    let score = |roles: &[Role]| roles.iter().map(|v| v.get_score(&world)).sum::<i32>();
+    let swapped = vec![Role::forward(1), Role::goalkeeper(2)];
+    let gap = score(&swapped) - score(&roles);
+
+    assert_eq!(gap, 448);
+    assert_eq!(get_optimal_roles(&robots_ids, &role_types, &roles, &world, gap - 1), swapped);
+    assert_eq!(get_optimal_roles(&robots_ids, &role_types, &roles, &world, gap), roles);
+}
+
+#[test]
+fn test_get_optimal_roles_keeps_role_that_can_not_quit() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::roles::{Role, RoleType, get_optimal_roles};
+    use my_strategy::my_strategy::vec3::Vec3;
+
+    let mut world = example_world(GameType::TwoRobots);
+    let robots_ids = vec![1, 2];
+    let role_types = vec![RoleType::Goalkeeper, RoleType::Forward];
+    let roles = vec![Role::goalkeeper(1), Role::forward(2)];
+
+    world.game.robots.iter_mut()
+        .find(|v| v.id == 1)
+        .map(|v| v.set_position(Vec3::new(25.0, 1.0, -38.0)));
+    world.game.robots.iter_mut()
+        .find(|v| v.id == 2)
+        .map(|v| v.set_position(Vec3::new(0.0, 1.0, -36.0)));
+
+    assert!(!roles[0].can_quit(&world));
+    assert_eq!(get_optimal_roles(&robots_ids, &role_types, &[], &world, 0), vec![Role::forward(1), Role::goalkeeper(2)]);
+    assert_eq!(get_optimal_roles(&robots_ids, &role_types, &roles, &world, 0), roles);
+}
+
+#[test]
+fn test_next_permutation() {
+    use my_strategy::my_strategy::assignment::next_permutation;
+
+    let mut values = vec![0, 1, 1];
+    let mut permutations = vec![values.clone()];
+
+    while next_permutation(&mut values) {
+        permutations.push(values.clone());
+    }
+
+    assert_eq!(permutations, vec![vec![0, 1, 1], vec![1, 0, 1], vec![1, 1, 0]]);
+}
@@ -0,0 +1,138 @@
+use my_strategy::my_strategy::simulator::Simulator;
+use my_strategy::my_strategy::ball_predictor::BallPredictor;
+use my_strategy::my_strategy::entity::Entity;
+use my_strategy::my_strategy::vec3::Vec3;
+use my_strategy::examples::{GameType, example_world, example_rng};
+
+#[test]
+fn test_ball_predictor_matches_simulation_without_robots() {
+    let mut world = example_world(GameType::TwoRobots);
+    world.game.ball.set_velocity(Vec3::new(10.0, 5.0, 20.0));
+    let ball_predictor = BallPredictor::new(&world);
+    let mut simulator = Simulator::new(&world, world.me.id);
+    let mut rng = example_rng(&world.rules);
+    simulator.robots_mut().iter_mut().for_each(|v| v.set_ignore(true));
+
+    for _ in 0..50 {
+        simulator.tick(
+            simulator.rules().tick_time_interval(),
+            world.config.near_micro_ticks_per_tick,
+            &mut rng,
+        );
+        assert!(ball_predictor.is_on_trajectory(
+            simulator.current_tick(),
+            simulator.ball().position(),
+            simulator.ball().velocity(),
+        ));
+    }
+
+    assert_eq!(
+        simulator.ball().position(),
+        Vec3::new(8.333333333333426, 2.2739573333333274, 16.666666666666853)
+    );
+}
+
+#[test]
+fn test_simulator_tick_predicted_ball() {
+    let mut world = example_world(GameType::TwoRobots);
+    world.game.ball.set_velocity(Vec3::new(10.0, 5.0, 20.0));
+    let ball_predictor = BallPredictor::new(&world);
+    let mut simulator = Simulator::new(&world, world.me.id);
+    simulator.robots_mut().iter_mut().for_each(|v| v.set_ignore(true));
+
+    for _ in 0..50 {
+        simulator.tick_predicted_ball(simulator.rules().tick_time_interval(), &ball_predictor);
+    }
+
+    assert_eq!(simulator.current_tick(), 50);
+    assert_eq!(simulator.current_micro_tick(), 50 * world.config.near_micro_ticks_per_tick as i32);
+    assert_eq!(
+        simulator.ball().position(),
+        Vec3::new(8.333333333333426, 2.2739573333333274, 16.666666666666853)
+    );
+}
+
+#[test]
+fn test_ball_predictor_opposite() {
+    let mut world = example_world(GameType::TwoRobots);
+    world.game.ball.set_velocity(Vec3::new(10.0, 5.0, 20.0));
+    let ball_predictor = BallPredictor::new(&world);
+    let opposite = BallPredictor::new(&world.opposite());
+
+    assert_eq!(
+        ball_predictor.opposite().get(30).unwrap().position,
+        opposite.get(30).unwrap().position
+    );
+}
+
+#[test]
+fn test_ball_predictor_keeps_arena_collision() {
+    use my_strategy::my_strategy::simulator::BallCollisionType;
+
+    let mut world = example_world(GameType::TwoRobots);
+    world.game.ball.set_velocity(Vec3::new(10.0, 5.0, 20.0));
+    let ball_predictor = BallPredictor::new(&world);
+    let tick = (1..=world.config.max_ticks)
+        .find(|v| ball_predictor.get(*v).unwrap().collision_type == BallCollisionType::Arena);
+
+    assert_eq!(tick, Some(49));
+}
+
+#[test]
+fn test_ball_predictor_advance() {
+    let mut world = example_world(GameType::TwoRobots);
+    world.game.ball.set_velocity(Vec3::new(10.0, 5.0, 20.0));
+    let ball_predictor = BallPredictor::new(&world);
+    let mut simulator = Simulator::new(&world, world.me.id);
+    let mut rng = example_rng(&world.rules);
+    simulator.robots_mut().iter_mut().for_each(|v| v.set_ignore(true));
+
+    for _ in 0..10 {
+        simulator.tick(
+            simulator.rules().tick_time_interval(),
+            world.config.near_micro_ticks_per_tick,
+            &mut rng,
+        );
+    }
+
+    world.game.current_tick += 10;
+    world.game.ball.set_position(simulator.ball().position());
+    world.game.ball.set_velocity(simulator.ball().velocity());
+
+    let advanced = ball_predictor.advance(&world).unwrap();
+    let rebuilt = BallPredictor::new(&world);
+
+    assert_eq!(advanced.tick(), world.game.current_tick);
+    assert!(advanced.is_on_trajectory(0, world.game.ball.position(), world.game.ball.velocity()));
+    assert_eq!(advanced.get(world.config.max_ticks).unwrap().position, rebuilt.get(world.config.max_ticks).unwrap().position);
+    assert!(advanced.get(world.config.max_ticks + 1).is_none());
+
+    world.game.ball.set_velocity(Vec3::new(0.0, 0.0, 0.0));
+
+    assert!(ball_predictor.advance(&world).is_none());
+}
+
+#[test]
+fn test_world_update_ball_predictor_micro_ticks() {
+    let mut world = example_world(GameType::TwoRobots);
+    world.config.use_ball_predictor = true;
+    world.game.ball.set_velocity(Vec3::new(10.0, 5.0, 20.0));
+
+    assert_eq!(world.update_ball_predictor(), 2500);
+
+    let mut simulator = Simulator::new(&world, world.me.id);
+    let mut rng = example_rng(&world.rules);
+    simulator.robots_mut().iter_mut().for_each(|v| v.set_ignore(true));
+    simulator.tick(
+        simulator.rules().tick_time_interval(),
+        world.config.near_micro_ticks_per_tick,
+        &mut rng,
+    );
+    let mut game = world.game.clone();
+    game.current_tick += 1;
+    game.ball.set_position(simulator.ball().position());
+    game.ball.set_velocity(simulator.ball().velocity());
+    let me = world.me.clone();
+
+    assert_eq!(world.update(&me, &game), 25);
+}
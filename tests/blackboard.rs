@@ -0,0 +1,60 @@
+#[test]
+fn test_blackboard_ball_touches() {
+    use my_strategy::my_strategy::blackboard::Blackboard;
+
+    let mut blackboard = Blackboard::new();
+
+    assert!(!blackboard.is_ball_claimed_by_teammate());
+
+    blackboard.claim_ball_touch(4, false, 0.5);
+    blackboard.claim_ball_touch(1, true, 0.25);
+
+    assert!(blackboard.is_ball_claimed_by_teammate());
+}
+
+#[test]
+fn test_blackboard_opponent_plan() {
+    use my_strategy::my_strategy::blackboard::{Blackboard, OpponentPlan};
+
+    let mut blackboard = Blackboard::new();
+    blackboard.set_opponent_plan(4, Some(1.0));
+    blackboard.set_opponent_plan(4, None);
+
+    assert_eq!(blackboard.get_opponent_plan(4), Some(&OpponentPlan { robot_id: 4, time_to_ball: None }));
+    assert_eq!(blackboard.get_opponent_plan(3), None);
+}
+
+#[test]
+fn test_blackboard_add_take_nitro_pack_order() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::blackboard::{Blackboard, Intention};
+    use my_strategy::my_strategy::orders::Order;
+    use my_strategy::my_strategy::common::IdGenerator;
+    use my_strategy::my_strategy::vec3::Vec3;
+
+    let mut world = example_world(GameType::TwoRobotsWithNitro);
+    let mut order_id_generator = IdGenerator::new();
+    let mut blackboard = Blackboard::new();
+    world.me.set_position(Vec3::new(-18.0, world.rules.ROBOT_RADIUS, -30.0));
+    let me_id = world.me.id;
+    let teammate = world.game.robots.iter_mut()
+        .find(|v| v.is_teammate && v.id != me_id)
+        .map(|v| {
+            v.set_position(Vec3::new(-20.0, v.y, -28.0));
+            v.clone()
+        })
+        .unwrap();
+    let teammate = &teammate;
+
+    let order = Order::try_take_nitro_pack(teammate, &world, &blackboard, std::f64::MAX.into(), &mut order_id_generator);
+    blackboard.add_order(&order, &world);
+
+    assert_eq!(blackboard.get_intention(teammate.id), Some(Intention::TakeNitroPack));
+    assert!(blackboard.is_nitro_pack_claimed(1, world.me.id));
+    assert!(!blackboard.is_nitro_pack_claimed(1, teammate.id));
+
+    match Order::try_take_nitro_pack(&world.me, &world, &blackboard, std::f64::MAX.into(), &mut order_id_generator) {
+        Order::TakeNitroPack(v) => assert_eq!(v.nitro_pack_id, 3),
+        _ => panic!("expected take nitro pack order"),
+    }
+}
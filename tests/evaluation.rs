@@ -0,0 +1,81 @@
+#[test]
+fn test_linear_evaluator_for_initial_state() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::simulator::Simulator;
+    use my_strategy::my_strategy::evaluation::{Evaluator, LinearEvaluator, Outcome};
+
+    let world = example_world(GameType::TwoRobots);
+    let simulator = Simulator::new(&world, world.me.id);
+    let evaluator = LinearEvaluator::new(&world.config);
+
+    let result = evaluator.evaluate(&Outcome {
+        simulator: &simulator,
+        target: world.rules.get_goal_target(),
+        max_time: 1.0,
+        my_time_to_ball: None,
+        opponent_time_to_ball: None,
+        time_to_goal: None,
+    });
+
+    assert_eq!(result, 0.7346244034040611);
+}
+
+#[test]
+fn test_plan_with_custom_evaluator() {
+    use std::rc::Rc;
+    use my_strategy::model::Action;
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::simulator::Simulator;
+    use my_strategy::my_strategy::evaluation::{Evaluator, Outcome};
+    use my_strategy::my_strategy::plan::Plan;
+
+    struct ConstEvaluator;
+
+    impl Evaluator for ConstEvaluator {
+        fn evaluate(&self, _: &Outcome) -> f64 {
+            0.5
+        }
+    }
+
+    let world = example_world(GameType::TwoRobots);
+    let get_robot_action_at = |_: i32, _: i32| -> Option<&Action> { None };
+    let plan = Plan::new(
+        &world.config,
+        0,
+        0,
+        Simulator::new(&world, world.me.id),
+        0.0,
+        std::f64::MAX.into(),
+        get_robot_action_at,
+        world.config.max_plan_micro_ticks,
+    ).with_evaluator(Rc::new(ConstEvaluator {}));
+
+    assert_eq!(plan.get_score(), 500);
+}
+
+#[test]
+fn test_learned_evaluator_for_initial_state() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::simulator::Simulator;
+    use my_strategy::my_strategy::learning::{Model, FEATURES_NUMBER};
+    use my_strategy::my_strategy::evaluation::{Evaluator, LearnedEvaluator, Outcome};
+
+    let world = example_world(GameType::TwoRobots);
+    let simulator = Simulator::new(&world, world.me.id);
+    let model: Model = serde_json::from_str(&format!(
+        r#"{{"layers":[{{"weights":[{:?}],"biases":[0.0],"activation":"Sigmoid"}}]}}"#,
+        (0..FEATURES_NUMBER).map(|v| if v == 2 { 1.0 } else { 0.0 }).collect::<Vec<f64>>()
+    )).unwrap();
+    let evaluator = LearnedEvaluator::new(model);
+
+    let result = evaluator.evaluate(&Outcome {
+        simulator: &simulator,
+        target: world.rules.get_goal_target(),
+        max_time: 1.0,
+        my_time_to_ball: None,
+        opponent_time_to_ball: None,
+        time_to_goal: None,
+    });
+
+    assert_eq!(result, 0.5);
+}
@@ -0,0 +1,136 @@
+#[test]
+fn test_default_fallback_policy_forward_takes_nitro_pack() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::orders::Order;
+    use my_strategy::my_strategy::roles::Role;
+    use my_strategy::my_strategy::blackboard::Blackboard;
+    use my_strategy::my_strategy::common::IdGenerator;
+
+    let world = example_world(GameType::TwoRobotsWithNitro);
+    let mut order_id_generator = IdGenerator::new();
+    let role = Role::forward(world.me.id);
+
+    let result = world.config.fallback_policy.give_order(
+        &role, &world.me, &world, &world.config, &Blackboard::new(), &mut order_id_generator
+    );
+
+    match result {
+        Order::TakeNitroPack(v) => assert_eq!(v.nitro_pack_id, 3),
+        _ => panic!("expected take nitro pack order"),
+    }
+}
+
+#[test]
+fn test_default_fallback_policy_goalkeeper_walks_to_goalkeeper_position() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::orders::Order;
+    use my_strategy::my_strategy::roles::Role;
+    use my_strategy::my_strategy::blackboard::Blackboard;
+    use my_strategy::my_strategy::common::IdGenerator;
+
+    let world = example_world(GameType::TwoRobotsWithNitro);
+    let mut order_id_generator = IdGenerator::new();
+    let role = Role::goalkeeper(world.me.id);
+
+    let result = world.config.fallback_policy.give_order(
+        &role, &world.me, &world, &world.config, &Blackboard::new(), &mut order_id_generator
+    );
+
+    match result {
+        Order::WalkToGoalkeeperPosition(_) => (),
+        _ => panic!("expected walk to goalkeeper position order"),
+    }
+}
+
+#[test]
+fn test_fallback_policy_from_json_checks_guards() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::orders::Order;
+    use my_strategy::my_strategy::roles::Role;
+    use my_strategy::my_strategy::blackboard::Blackboard;
+    use my_strategy::my_strategy::common::IdGenerator;
+    use my_strategy::my_strategy::fallback::FallbackPolicy;
+
+    let policy: FallbackPolicy = serde_json::from_str(r#"{
+        "goalkeeper": [],
+        "defender": [],
+        "midfielder": [],
+        "forward": [
+            {"order": "WalkToDefenderPosition", "guards": [{"MinTeamSize": 3}]},
+            {"order": "WalkToMidfielderPosition", "guards": ["NitroBelowMax"]}
+        ]
+    }"#).unwrap();
+    let world = example_world(GameType::TwoRobotsWithNitro);
+    let mut order_id_generator = IdGenerator::new();
+
+    let result = policy.give_order(
+        &Role::forward(world.me.id), &world.me, &world, &world.config, &Blackboard::new(), &mut order_id_generator
+    );
+
+    match result {
+        Order::WalkToPosition(v) => assert_eq!(v.name, "walk_to_midfielder_position"),
+        _ => panic!("expected walk to midfielder position order"),
+    }
+
+    let result = policy.give_order(
+        &Role::goalkeeper(world.me.id), &world.me, &world, &world.config, &Blackboard::new(), &mut order_id_generator
+    );
+
+    assert!(result.is_idle());
+}
+
+#[test]
+fn test_config_json_fallback_policy_is_default() {
+    use my_strategy::my_strategy::config::Config;
+    use my_strategy::my_strategy::fallback::FallbackPolicy;
+
+    let config: Config = serde_json::from_str(include_str!("../etc/config.json")).unwrap();
+
+    assert_eq!(config.fallback_policy, FallbackPolicy::new());
+}
+
+#[test]
+fn test_fallback_policy_checks_opponent_profile_guards() {
+    use std::rc::Rc;
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::orders::Order;
+    use my_strategy::my_strategy::roles::Role;
+    use my_strategy::my_strategy::blackboard::Blackboard;
+    use my_strategy::my_strategy::common::IdGenerator;
+    use my_strategy::my_strategy::fallback::FallbackPolicy;
+    use my_strategy::my_strategy::opponent_profile::OpponentProfile;
+
+    let policy: FallbackPolicy = serde_json::from_str(r#"{
+        "goalkeeper": [],
+        "defender": [],
+        "midfielder": [],
+        "forward": [
+            {"order": "WalkToDefenderPosition", "guards": [{"OpponentGoalkeeperRateAbove": 0.5}]},
+            {"order": "WalkToMidfielderPosition", "guards": [{"OpponentNitroUsageAbove": 0.0}]}
+        ]
+    }"#).unwrap();
+    let mut world = example_world(GameType::TwoRobotsWithNitro);
+    let mut order_id_generator = IdGenerator::new();
+
+    let result = policy.give_order(
+        &Role::forward(world.me.id), &world.me, &world, &world.config, &Blackboard::new(), &mut order_id_generator
+    );
+
+    assert!(result.is_idle());
+
+    let mut profile = OpponentProfile::new();
+    profile.update(&world.game, &world.rules);
+    world.game.current_tick += 1;
+    world.game.robots.iter_mut().filter(|v| !v.is_teammate).for_each(|v| v.nitro_amount -= 1.0);
+    profile.update(&world.game, &world.rules);
+    world.opponent_profile = Some(Rc::new(profile));
+
+    let result = policy.give_order(
+        &Role::forward(world.me.id), &world.me, &world, &world.config, &Blackboard::new(), &mut order_id_generator
+    );
+
+    match result {
+        Order::WalkToPosition(v) => assert_eq!(v.name, "walk_to_midfielder_position"),
+        _ => panic!("expected walk to midfielder position order"),
+    }
+}
@@ -0,0 +1,61 @@
+#[test]
+fn test_model_predict() {
+    use my_strategy::my_strategy::learning::Model;
+
+    let model: Model = serde_json::from_str(r#"{"layers":[
+        {"weights":[[1.0,-1.0],[0.5,0.5]],"biases":[0.0,-1.0],"activation":"Relu"},
+        {"weights":[[1.0,2.0]],"biases":[-0.5],"activation":"Sigmoid"}
+    ]}"#).unwrap();
+
+    assert_eq!(model.predict(&[2.0, 1.0]), 0.8175744761936437);
+}
+
+#[test]
+fn test_model_fit() {
+    use my_strategy::my_strategy::random::{XorShiftRng, SeedableRng};
+    use my_strategy::my_strategy::learning::Model;
+    use my_strategy::training::Sample;
+
+    let mut rng = XorShiftRng::from_seed([2960285387, 943075939, 3311701793, 474463886]);
+    let mut samples: Vec<Sample> = (0..20)
+        .map(|i| {
+            let x = i as f64 / 10.0 - 1.0;
+            Sample { features: vec![x, 1.0 - x], label: if x > 0.0 { 1.0 } else { 0.0 } }
+        })
+        .collect();
+    let mut model = Model::new(2, &[4], &mut rng);
+    let initial_loss = model.loss(&samples);
+
+    model.fit(&mut samples, 200, 0.1, &mut rng);
+
+    assert!(model.loss(&samples) < initial_loss);
+    assert!(model.predict(&[0.9, 0.1]) > 0.5);
+    assert!(model.predict(&[-0.9, 1.9]) < 0.5);
+}
+
+#[test]
+fn test_model_deserialize_validates_layers() {
+    use my_strategy::my_strategy::learning::Model;
+
+    assert!(serde_json::from_str::<Model>(r#"{"layers":[]}"#).is_err());
+    assert!(serde_json::from_str::<Model>(r#"{"layers":[
+        {"weights":[[1.0,-1.0],[0.5,0.5]],"biases":[0.0],"activation":"Relu"},
+        {"weights":[[1.0,2.0]],"biases":[-0.5],"activation":"Sigmoid"}
+    ]}"#).is_err());
+    assert!(serde_json::from_str::<Model>(r#"{"layers":[
+        {"weights":[[1.0,-1.0],[0.5,0.5]],"biases":[0.0,-1.0],"activation":"Relu"},
+        {"weights":[[1.0,2.0,3.0]],"biases":[-0.5],"activation":"Sigmoid"}
+    ]}"#).is_err());
+    assert!(serde_json::from_str::<Model>(r#"{"layers":[
+        {"weights":[[1.0,-1.0],[0.5,0.5]],"biases":[0.0,-1.0],"activation":"Sigmoid"}
+    ]}"#).is_err());
+}
+
+#[test]
+fn test_evaluator_config_rejects_model_with_wrong_features_number() {
+    use my_strategy::my_strategy::config::EvaluatorConfig;
+
+    assert!(serde_json::from_str::<EvaluatorConfig>(
+        r#"{"Learned":{"layers":[{"weights":[[1.0,2.0]],"biases":[0.0],"activation":"Sigmoid"}]}}"#
+    ).is_err());
+}
@@ -48,9 +48,9 @@ fn test_two_robots_first_action_to_go_for_ball() {
     let mut action = Action::default();
     my_strategy.act(simulator.me().base(), simulator.rules(), &simulator.game(), &mut action);
     assert_eq!(action, Action {
-        target_velocity_x: 16.247834600650872,
+        target_velocity_x: 16.43240891806013,
         target_velocity_y: 0.0,
-        target_velocity_z: 25.21919647391432,
+        target_velocity_z: 25.09932144799253,
         jump_speed: 0.0,
         use_nitro: false,
     });
@@ -77,7 +77,7 @@ fn test_two_robots_first_ball_kick_until_goal() {
     });
 
     assert_eq!(simulator.score(), 1);
-    assert_eq!(simulator.current_tick(), 113);
+    assert_eq!(simulator.current_tick(), 108);
 }
 
 #[test]
@@ -195,7 +195,122 @@ fn test_three_robots_with_nitro_first_ball_kick_until_goal() {
     });
 
     assert_eq!(simulator.score(), 1);
-    assert_eq!(simulator.current_tick(), 107);
+    assert_eq!(simulator.current_tick(), 115);
+}
+
+#[test]
+fn test_three_robots_with_nitro_first_ball_kick_until_goal_predicting_all_opponents() {
+    use my_strategy::examples::{GameType, example_world, example_rng};
+    use my_strategy::my_strategy::simulator::Simulator;
+    use my_strategy::my_strategy::my_strategy_impl::MyStrategyImpl;
+
+    let mut world = example_world(GameType::ThreeRobotsWithNitro);
+    world.config.max_predicted_opponents = 3;
+    world.config.opponent_prediction_max_distance = 30.0;
+    world.config.max_jump_refinement_iterations = 0;
+    let mut rng = example_rng(&world.rules);
+    let mut simulator = Simulator::new(&world, 3);
+    let mut my_strategy = MyStrategyImpl::new(
+        world.config,
+        simulator.me().base(),
+        simulator.rules(),
+        &simulator.game(),
+    );
+
+    simulate_while(&mut my_strategy, &mut simulator, &mut rng, |simulator| {
+        simulator.score() == 0 && simulator.current_tick() < 150
+    });
+
+    assert_eq!(simulator.score(), 1);
+    assert_eq!(simulator.current_tick(), 110);
+}
+
+#[test]
+fn test_three_robots_with_nitro_defensive_formation_first_action_to_go_to_defender_position() {
+    use my_strategy::model::Action;
+    use my_strategy::strategy::Strategy;
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::simulator::Simulator;
+    use my_strategy::my_strategy::my_strategy_impl::MyStrategyImpl;
+    use my_strategy::my_strategy::roles::Formation;
+
+    let mut world = example_world(GameType::ThreeRobotsWithNitro);
+    world.config.formation = Formation::Defensive;
+    let simulator = Simulator::new(&world, 3);
+    let mut my_strategy = MyStrategyImpl::new(
+        world.config,
+        simulator.me().base(),
+        simulator.rules(),
+        &simulator.game(),
+    );
+    let mut action = Action::default();
+    my_strategy.act(simulator.me().base(), simulator.rules(), &simulator.game(), &mut action);
+    assert_eq!(action, Action {
+        target_velocity_x: -5.861709292708341,
+        target_velocity_y: 0.0,
+        target_velocity_z: -29.42176684306666,
+        jump_speed: 0.0,
+        use_nitro: false,
+    });
+}
+
+#[test]
+fn test_two_robots_first_action_from_kickoff_playbook() {
+    use my_strategy::model::Action;
+    use my_strategy::strategy::Strategy;
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::simulator::Simulator;
+    use my_strategy::my_strategy::my_strategy_impl::MyStrategyImpl;
+    use my_strategy::my_strategy::vec3::Vec3;
+    use my_strategy::my_strategy::kickoff::{KickoffPlan, KickoffRobotPlan};
+
+    let mut world = example_world(GameType::TwoRobots);
+    let kickoff_action = |target_velocity_z: f64| Action {
+        target_velocity_x: 0.0,
+        target_velocity_y: 0.0,
+        target_velocity_z,
+        jump_speed: 0.0,
+        use_nitro: false,
+    };
+    world.config.kickoff_playbook = vec![KickoffPlan {
+        team_size: 2,
+        nitro: false,
+        opponents: world.game.robots.iter()
+            .filter(|v| !v.is_teammate)
+            .map(|v| v.position() + Vec3::new(1.0, 0.0, 0.0))
+            .collect(),
+        robots: world.game.robots.iter()
+            .filter(|v| v.is_teammate)
+            .map(|v| KickoffRobotPlan {
+                position: v.position(),
+                actions: vec![kickoff_action(v.id as f64); 10],
+            })
+            .collect(),
+    }];
+    let simulator = Simulator::new(&world, 1);
+    let mut my_strategy = MyStrategyImpl::new(
+        world.config,
+        simulator.me().base(),
+        simulator.rules(),
+        &simulator.game(),
+    );
+    let mut action = Action::default();
+    my_strategy.act(simulator.me().base(), simulator.rules(), &simulator.game(), &mut action);
+    assert_eq!(action, kickoff_action(1.0));
+}
+
+#[test]
+fn test_config_json_kickoff_playbook_has_plans_for_example_worlds() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::config::Config;
+
+    let config: Config = serde_json::from_str(include_str!("../etc/config.json")).unwrap();
+
+    for &game_type in [GameType::TwoRobots, GameType::TwoRobotsWithNitro, GameType::ThreeRobotsWithNitro].iter() {
+        let world = example_world(game_type);
+
+        assert!(config.kickoff_playbook.iter().any(|v| v.is_suitable(&world)), "{:?}", game_type);
+    }
 }
 
 fn simulate_while<P>(my_strategy: &mut MyStrategyImpl, simulator: &mut Simulator,
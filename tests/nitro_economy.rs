@@ -0,0 +1,62 @@
+#[test]
+fn test_get_available_time() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::nitro_economy::get_available_time;
+
+    let mut world = example_world(GameType::TwoRobotsWithNitro);
+    world.game.nitro_packs[0].respawn_ticks = Some(30);
+
+    assert_eq!(get_available_time(&world.game.nitro_packs[0], &world.rules), 0.5);
+    assert_eq!(get_available_time(&world.game.nitro_packs[1], &world.rules), 0.0);
+}
+
+#[test]
+fn test_is_taken_by_opponent() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::nitro_economy::is_taken_by_opponent;
+
+    let mut world = example_world(GameType::TwoRobotsWithNitro);
+    let nitro_pack_position = world.game.nitro_packs[0].position();
+    world.game.robots.iter_mut()
+        .find(|v| !v.is_teammate)
+        .map(|v| v.set_position(nitro_pack_position.with_y(v.y)));
+
+    assert!(is_taken_by_opponent(&world.me, &world.game.nitro_packs[0], &world));
+    assert!(!is_taken_by_opponent(&world.me, &world.game.nitro_packs[2], &world));
+}
+
+#[test]
+fn test_is_detour_worth() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::nitro_economy::is_detour_worth;
+
+    let world = example_world(GameType::TwoRobotsWithNitro);
+
+    assert!(is_detour_worth(&world.me, &world.game.nitro_packs[2], &world));
+    assert!(!is_detour_worth(&world.me, &world.game.nitro_packs[0], &world));
+}
+
+#[test]
+fn test_assign_nitro_packs() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::nitro_economy::assign_nitro_packs;
+    use my_strategy::my_strategy::zone::Zone;
+
+    let world = example_world(GameType::ThreeRobotsWithNitro);
+
+    assert_eq!(assign_nitro_packs(&world, |_| Zone::from(std::f64::MAX)), vec![(1, 3), (3, 1)]);
+}
+
+#[test]
+fn test_assign_nitro_packs_in_zone() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::model::Robot;
+    use my_strategy::my_strategy::nitro_economy::assign_nitro_packs;
+    use my_strategy::my_strategy::zone::Zone;
+
+    let world = example_world(GameType::ThreeRobotsWithNitro);
+
+    let get_zone = |robot: &Robot| if robot.id == 1 { Zone::from(-30.0) } else { Zone::from(std::f64::MAX) };
+
+    assert_eq!(assign_nitro_packs(&world, get_zone), vec![(2, 3), (3, 1)]);
+}
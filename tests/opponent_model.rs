@@ -0,0 +1,109 @@
+#[test]
+fn test_fit_opponent_model_by_observations() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::vec3::Vec3;
+    use my_strategy::my_strategy::random::{XorShiftRng, SeedableRng};
+    use my_strategy::my_strategy::simulator::Simulator;
+    use my_strategy::training::{Observation, RobotObservation, fit_opponent_model_by_observations};
+
+    let mut world = example_world(GameType::TwoRobots);
+    let rules = world.rules.clone();
+    let position = Vec3::new(5.0, 1.0, 0.0);
+    let ball_position = |tick: i32| Vec3::new(0.0, 2.0, (tick % 40 - 20) as f64);
+    let velocity = |tick: i32| {
+        let target = if ball_position(tick).z() < 0.0 {
+            ball_position(tick)
+        } else {
+            rules.get_my_goal_target()
+        };
+        (target - position).with_y(0.0).normalized() * rules.ROBOT_MAX_GROUND_SPEED * 0.8
+    };
+    let observations: Vec<Observation> = (0..400)
+        .map(|tick| Observation {
+            current_tick: tick,
+            ball_position: ball_position(tick),
+            robots: vec![RobotObservation {
+                id: 3,
+                position,
+                velocity: if tick > 0 { velocity(tick - 1) } else { Vec3::default() },
+            }],
+        })
+        .collect();
+    let mut rng = XorShiftRng::from_seed([2960285387, 943075939, 3311701793, 474463886]);
+
+    let model = fit_opponent_model_by_observations(&observations, &rules, 100, 0.1, &mut rng);
+
+    assert_eq!(model.speed_factor, 0.8000000000000059);
+    assert_eq!(model.jump_distance, rules.ball_distance_limit());
+
+    let opponent_id = world.game.robots.iter().find(|v| !v.is_teammate).unwrap().id;
+    world.game.robots.iter_mut()
+        .find(|v| v.id == opponent_id)
+        .map(|v| v.set_position(position.opposite()));
+
+    world.game.ball.set_position(ball_position(5).opposite());
+    let simulator = Simulator::new(&world, world.me.id);
+    let action = model.get_action(opponent_id, &simulator);
+    let to_ball = world.game.ball.position() - position.opposite();
+    assert!(action.target_velocity().cos(to_ball.with_y(0.0)) > 0.99);
+
+    world.game.ball.set_position(ball_position(25).opposite());
+    let simulator = Simulator::new(&world, world.me.id);
+    let action = model.get_action(opponent_id, &simulator);
+    assert!(action.target_velocity().z() > 0.0);
+}
+
+#[test]
+fn test_opponent_model_get_action() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::vec3::Vec3;
+    use my_strategy::my_strategy::simulator::Simulator;
+    use my_strategy::my_strategy::learning::{Model, Layer, Activation};
+    use my_strategy::my_strategy::opponent_model::OpponentModel;
+
+    let mut world = example_world(GameType::TwoRobots);
+    let rules = world.rules.clone();
+    let position = Vec3::new(5.0, 1.0, 0.0);
+    let ball_position = |tick: i32| Vec3::new(0.0, 2.0, (tick % 40 - 20) as f64);
+    let model = OpponentModel {
+        intercept: Model {
+            layers: vec![Layer {
+                weights: vec![vec![0.0, 0.0, -10.0, 0.0, 0.0, 0.0]],
+                biases: vec![0.0],
+                activation: Activation::Sigmoid,
+            }],
+        },
+        speed_factor: 0.8,
+        jump_distance: rules.ball_distance_limit(),
+    };
+
+    let opponent_id = world.game.robots.iter().find(|v| !v.is_teammate).unwrap().id;
+    world.game.robots.iter_mut()
+        .find(|v| v.id == opponent_id)
+        .map(|v| v.set_position(position.opposite()));
+
+    world.game.ball.set_position(ball_position(5).opposite());
+    let simulator = Simulator::new(&world, world.me.id);
+    let action = model.get_action(opponent_id, &simulator);
+    assert_eq!(action.target_velocity().norm(), 24.000000000000004);
+    let to_ball = world.game.ball.position() - position.opposite();
+    assert!(action.target_velocity().cos(to_ball.with_y(0.0)) > 0.99);
+
+    world.game.ball.set_position(ball_position(25).opposite());
+    let simulator = Simulator::new(&world, world.me.id);
+    let action = model.get_action(opponent_id, &simulator);
+    assert!(action.target_velocity().z() > 0.0);
+}
+
+#[test]
+fn test_opponent_model_deserialize_validates_features_number() {
+    use my_strategy::my_strategy::opponent_model::OpponentModel;
+
+    let model = |weights: &str| format!(
+        r#"{{"intercept":{{"layers":[{{"weights":[{}],"biases":[0.0],"activation":"Sigmoid"}}]}},"speed_factor":0.8,"jump_distance":3.0}}"#,
+        weights
+    );
+
+    assert!(serde_json::from_str::<OpponentModel>(&model("[0.0,0.0,-10.0,0.0,0.0,0.0]")).is_ok());
+    assert!(serde_json::from_str::<OpponentModel>(&model("[0.0,0.0,-10.0]")).is_err());
+}
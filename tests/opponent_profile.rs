@@ -0,0 +1,109 @@
+#[test]
+fn test_opponent_profile_update() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::opponent_profile::OpponentProfile;
+
+    let mut world = example_world(GameType::TwoRobotsWithNitro);
+    let mut profile = OpponentProfile::new();
+
+    profile.update(&world.game, &world.rules);
+
+    let ball_position = world.game.ball.position();
+    let max_speed = world.rules.ROBOT_MAX_GROUND_SPEED;
+    for robot in world.game.robots.iter_mut().filter(|v| !v.is_teammate) {
+        let velocity = (ball_position - robot.position()).with_y(0.0).normalized() * max_speed;
+        robot.set_velocity(velocity);
+    }
+    world.game.current_tick += 1;
+
+    profile.update(&world.game, &world.rules);
+
+    let opponent_id = world.game.robots.iter().find(|v| !v.is_teammate).unwrap().id;
+
+    assert_eq!(profile.ticks, 2);
+    assert_eq!(profile.speed_to_ball(), 14.193497653891864);
+    assert_eq!(profile.aggression(&world.rules), 0.47311658846306215);
+    assert_eq!(profile.get_robot(opponent_id).unwrap().ticks, 2);
+    assert_eq!(profile.get_robot(opponent_id).unwrap().speed_to_ball(), 14.193497653891862);
+    assert!(profile.get_robot(world.me.id).is_none());
+
+    world.config.opponent_profile_aggression_penalty_factor = 1.0;
+
+    assert_eq!(profile.get_opponent_time_to_ball_penalty_weight(&world.config, &world.rules), 0.1472980830256117);
+    assert_eq!(profile.get_threat_factor(opponent_id, &world.config, &world.rules), 1.4731165884630621);
+    assert_eq!(profile.get_threat_factor(world.me.id, &world.config, &world.rules), 1.0);
+}
+
+#[test]
+fn test_opponent_profile_jump_frequency() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::opponent_profile::OpponentProfile;
+
+    let mut world = example_world(GameType::TwoRobotsWithNitro);
+    let mut profile = OpponentProfile::new();
+    let opponent_id = world.game.robots.iter().find(|v| !v.is_teammate).unwrap().id;
+
+    world.game.robots.iter_mut().for_each(|v| v.touch = true);
+    profile.update(&world.game, &world.rules);
+
+    world.game.current_tick += 1;
+    world.game.robots.iter_mut().find(|v| v.id == opponent_id).unwrap().velocity_y = world.rules.ROBOT_MAX_JUMP_SPEED;
+    profile.update(&world.game, &world.rules);
+
+    assert_eq!(profile.get_robot(opponent_id).unwrap().jumps, 1);
+    assert_eq!(profile.jump_frequency(&world.rules), 30.0);
+
+    world.game.current_tick += 2;
+    world.game.robots.iter_mut().find(|v| v.id == opponent_id).unwrap().velocity_y = 0.0;
+    profile.update(&world.game, &world.rules);
+    world.game.robots.iter_mut().find(|v| v.id == opponent_id).unwrap().velocity_y = world.rules.ROBOT_MAX_JUMP_SPEED;
+    world.game.current_tick += 2;
+    profile.update(&world.game, &world.rules);
+
+    assert_eq!(profile.get_robot(opponent_id).unwrap().jumps, 1);
+}
+
+#[test]
+fn test_opponent_profile_nitro_usage() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::opponent_profile::OpponentProfile;
+
+    let mut world = example_world(GameType::TwoRobotsWithNitro);
+    let mut profile = OpponentProfile::new();
+    let opponent_id = world.game.robots.iter().find(|v| !v.is_teammate).unwrap().id;
+
+    profile.update(&world.game, &world.rules);
+
+    world.game.current_tick += 1;
+    world.game.robots.iter_mut().find(|v| v.id == opponent_id).unwrap().nitro_amount -= 10.0;
+    profile.update(&world.game, &world.rules);
+
+    assert_eq!(profile.get_robot(opponent_id).unwrap().nitro_spent, 10.0);
+    assert_eq!(profile.nitro_usage(&world.rules), 300.0);
+
+    world.game.current_tick += 1;
+    world.game.robots.iter_mut().find(|v| v.id == opponent_id).unwrap().nitro_amount += 20.0;
+    profile.update(&world.game, &world.rules);
+
+    assert_eq!(profile.get_robot(opponent_id).unwrap().nitro_spent, 10.0);
+}
+
+#[test]
+fn test_opponent_profile_goalkeeper_rate() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::opponent_profile::OpponentProfile;
+
+    let mut world = example_world(GameType::TwoRobotsWithNitro);
+    let mut profile = OpponentProfile::new();
+    let opponent_id = world.game.robots.iter().find(|v| !v.is_teammate).unwrap().id;
+
+    profile.update(&world.game, &world.rules);
+
+    let goal_position = world.rules.get_goal_target().with_y(1.0);
+    world.game.current_tick += 1;
+    world.game.robots.iter_mut().find(|v| v.id == opponent_id).unwrap().set_position(goal_position);
+    profile.update(&world.game, &world.rules);
+
+    assert_eq!(profile.get_robot(opponent_id).unwrap().goalkeeper_ticks, 1);
+    assert_eq!(profile.goalkeeper_rate(), 0.5);
+}
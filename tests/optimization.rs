@@ -0,0 +1,20 @@
+#[test]
+fn test_minimize1d() {
+    use my_strategy::my_strategy::optimization::minimize1d;
+
+    let result = minimize1d(-10.0, 10.0, 100, |x| (x - 3.0).powi(2));
+
+    assert!((result - 3.0).abs() < 1e-6, "{}", result);
+}
+
+#[test]
+fn test_minimize_nelder_mead() {
+    use my_strategy::my_strategy::optimization::minimize_nelder_mead;
+
+    let result = minimize_nelder_mead(&[0.0, 0.0], &[1.0, 1.0], 200, |x| {
+        (x[0] - 1.0).powi(2) + 2.0 * (x[1] + 2.0).powi(2)
+    });
+
+    assert!((result[0] - 1.0).abs() < 1e-6, "{:?}", result);
+    assert!((result[1] + 2.0).abs() < 1e-6, "{:?}", result);
+}
@@ -19,11 +19,11 @@ fn test_try_play() {
         micro_ticks: &mut micro_ticks,
     };
 
-    let result = Order::try_play(&world.me, &world, &[], std::f64::MAX, &mut ctx);
+    let result = Order::try_play(&world.me, &world, &[], f64::MAX.into(), &mut ctx);
 
-    assert_eq!(result.score(), 1227);
+    assert_eq!(result.score(), 1254);
     assert_eq!(result.action().jump_speed, 0.0);
-    assert_eq!(result.action().target_velocity(), Vec3::new(-14.622886891738023, 0.0, 26.194869324954386));
+    assert_eq!(result.action().target_velocity(), Vec3::new(-15.64254574316403, 0.0, 25.53162523220194));
 
     #[cfg(feature = "enable_stats")]
     assert_eq!(result.stats(), &Stats {
@@ -91,11 +91,11 @@ fn test_try_play_should_not_jump_on_ball_top() {
     world.game.ball.y = 5.233161866399729;
     world.game.ball.velocity_y = -12.500000000000554;
 
-    let result = Order::try_play(&world.me, &world, &[], std::f64::MAX, &mut ctx);
+    let result = Order::try_play(&world.me, &world, &[], f64::MAX.into(), &mut ctx);
 
-    assert_eq!(result.score(), 1471);
+    assert_eq!(result.score(), 1472);
     assert_eq!(result.action().jump_speed, 0.0);
-    assert_eq!(result.action().target_velocity(), Vec3::new(16.985123857184913, 0.0, 24.72863861105354));
+    assert_eq!(result.action().target_velocity(), Vec3::new(16.65590980596573, 0.0, 24.95156645454419));
 
     #[cfg(feature = "enable_stats")]
     assert_eq!(result.stats(), &Stats {
@@ -163,7 +163,7 @@ fn test_try_play_far_jump() {
     world.game.ball.y = 6.0;
     world.game.ball.velocity_y = 0.0;
 
-    let result = Order::try_play(&world.me, &world, &[], std::f64::MAX, &mut ctx);
+    let result = Order::try_play(&world.me, &world, &[], f64::MAX.into(), &mut ctx);
 
     assert_eq!(result.score(), 1362);
     assert_eq!(result.action().jump_speed, 15.0);
@@ -229,7 +229,7 @@ fn test_try_play_continue_jump() {
     world.game.ball.y = 2.123101000000013;
     world.game.ball.velocity_y = 12.815500000000347;
 
-    let result = Order::try_play(&world.me, &world, &[], std::f64::MAX, &mut ctx);
+    let result = Order::try_play(&world.me, &world, &[], f64::MAX.into(), &mut ctx);
 
     assert_eq!(result.score(), 3018);
     assert_eq!(result.action().jump_speed, 15.0);
@@ -295,7 +295,7 @@ fn test_try_play_continue_jump_with_nitro() {
     world.game.ball.y = 2.123101000000013;
     world.game.ball.velocity_y = 12.815500000000347;
 
-    let result = Order::try_play(&world.me, &world, &[], std::f64::MAX, &mut ctx);
+    let result = Order::try_play(&world.me, &world, &[], f64::MAX.into(), &mut ctx);
 
     assert_eq!(result.score(), 3054);
     assert_eq!(result.action().use_nitro, true);
@@ -362,11 +362,11 @@ fn test_try_play_when_far_from_ball_at_my_side() {
         micro_ticks: &mut micro_ticks,
     };
 
-    let result = Order::try_play(&world.me, &world, &[], std::f64::MAX, &mut ctx);
+    let result = Order::try_play(&world.me, &world, &[], f64::MAX.into(), &mut ctx);
 
-    assert_eq!(result.score(), 951);
+    assert_eq!(result.score(), 954);
     assert_eq!(result.action().jump_speed, 0.0);
-    assert_eq!(result.action().target_velocity(), Vec3::new(-16.641005886756876, 0.0, 24.961508830135312));
+    assert_eq!(result.action().target_velocity(), Vec3::new(-16.640814325984692, 0.0, 24.961636536255043));
 
     #[cfg(feature = "enable_stats")]
     assert_eq!(result.stats(), &Stats {
@@ -427,11 +427,11 @@ fn test_try_play_when_far_from_ball_at_opponent_side() {
         micro_ticks: &mut micro_ticks,
     };
 
-    let result = Order::try_play(&world.me, &world, &[], std::f64::MAX, &mut ctx);
+    let result = Order::try_play(&world.me, &world, &[], f64::MAX.into(), &mut ctx);
 
-    assert_eq!(result.score(), 394);
+    assert_eq!(result.score(), 395);
     assert_eq!(result.action().jump_speed, 0.0);
-    assert_eq!(result.action().target_velocity(), Vec3::new(16.641005886756876, 0.0, -24.961508830135312));
+    assert_eq!(result.action().target_velocity(), Vec3::new(16.641005886853, 0.0, -24.961093778223674));
 
     #[cfg(feature = "enable_stats")]
     assert_eq!(result.stats(), &Stats {
@@ -493,7 +493,7 @@ fn test_try_play_goalkeeper_should_catch_but_cant() {
         .find(|v| v.id == me.id)
         .map(|v| *v = me.clone());
 
-    let result = Order::try_play(&world.me, &world, &[], Goalkeeper::max_z(&world), &mut ctx);
+    let result = Order::try_play(&world.me, &world, &[], Goalkeeper::max_z(&world).into(), &mut ctx);
 
     assert_eq!(result.score(), -995);
     assert_eq!(result.action().use_nitro, false);
@@ -561,7 +561,7 @@ fn test_try_play_goalkeeper_should_catch() {
     world.game.ball.set_position(Vec3::new(2.6660784257613335, 8.492895589287127, -22.298092658424864));
     world.game.ball.set_velocity(Vec3::new(5.10521022216499, 0.1258312833164129, -42.698087751137));
 
-    let result = Order::try_play(&world.me, &world, &[], Goalkeeper::max_z(&world), &mut ctx);
+    let result = Order::try_play(&world.me, &world, &[], Goalkeeper::max_z(&world).into(), &mut ctx);
 
     assert_eq!(result.score(), 963);
     assert_eq!(result.action().use_nitro, false);
@@ -618,11 +618,11 @@ fn test_try_play_for_tree_robots_with_nitro() {
         micro_ticks: &mut micro_ticks,
     };
 
-    let result = Order::try_play(&world.me, &world, &[], std::f64::MAX, &mut ctx);
+    let result = Order::try_play(&world.me, &world, &[], f64::MAX.into(), &mut ctx);
 
-    assert_eq!(result.score(), 1289);
+    assert_eq!(result.score(), 1304);
     assert_eq!(result.action().jump_speed, 0.0);
-    assert_eq!(result.action().target_velocity(), Vec3::new(-16.314604599267437, 0.0, 25.17605363772412));
+    assert_eq!(result.action().target_velocity(), Vec3::new(-15.675609665026748, 0.0, 25.578804929662372));
 
     #[cfg(feature = "enable_stats")]
     assert_eq!(result.stats(), &Stats {
@@ -652,3 +652,495 @@ fn test_try_play_for_tree_robots_with_nitro() {
         path: vec!["fork_ball", "walk_to_position", "jump", "watch_me_jump", "watch_ball_move"],
     });
 }
+
+#[test]
+fn test_try_play_with_threat_penalty() {
+    use my_strategy::examples::{GameType, example_world, example_rng};
+    use my_strategy::my_strategy::vec3::Vec3;
+    use my_strategy::my_strategy::orders::{Order, Context};
+    use my_strategy::my_strategy::common::IdGenerator;
+
+    let mut world = example_world(GameType::TwoRobots);
+    world.config.threat_penalty_weight = 1.0;
+    world.game.ball.set_position(Vec3::new(0.0, 3.0, 12.0));
+    let mut rng = example_rng(&world.rules);
+    let mut order_id_generator = IdGenerator::new();
+    let mut micro_ticks = 0;
+    let mut ctx = Context {
+        config: &world.config,
+        rng: &mut rng,
+        order_id_generator: &mut order_id_generator,
+        micro_ticks: &mut micro_ticks,
+    };
+
+    let result = Order::try_play(&world.me, &world, &[], f64::MAX.into(), &mut ctx);
+
+    assert_eq!(result.score(), 866);
+    assert_eq!(result.action().jump_speed, 0.0);
+    assert_eq!(result.action().target_velocity(), Vec3::new(-9.423741987675513, 0.0, 28.48145162995948));
+}
+
+#[test]
+fn test_try_play_with_ball_predictor() {
+    use my_strategy::examples::{GameType, example_world, example_rng};
+    use my_strategy::my_strategy::vec3::Vec3;
+    use my_strategy::my_strategy::orders::{Order, Context};
+    use my_strategy::my_strategy::common::IdGenerator;
+
+    let mut world = example_world(GameType::OneRobotWithNitro);
+    world.config.use_ball_predictor = true;
+    world.game.ball.set_position(Vec3::new(0.0, 12.0, 5.0));
+    world.update_ball_predictor();
+    let mut rng = example_rng(&world.rules);
+    let mut order_id_generator = IdGenerator::new();
+    let mut micro_ticks = 0;
+    let mut ctx = Context {
+        config: &world.config,
+        rng: &mut rng,
+        order_id_generator: &mut order_id_generator,
+        micro_ticks: &mut micro_ticks,
+    };
+
+    let result = Order::try_play(&world.me, &world, &[], f64::MAX.into(), &mut ctx);
+
+    assert_eq!(result.score(), 1297);
+    assert_eq!(result.action().jump_speed, 0.0);
+    assert_eq!(result.action().target_velocity(), Vec3::new(-12.910330569719445, 0.0, 27.079943954531505));
+    assert_eq!(micro_ticks, 10225);
+}
+
+#[test]
+fn test_commit_keeps_previous_play() {
+    use my_strategy::examples::{GameType, example_world, example_rng};
+    use my_strategy::my_strategy::orders::{Order, Context};
+    use my_strategy::my_strategy::common::IdGenerator;
+
+    let mut world = example_world(GameType::TwoRobots);
+    let mut rng = example_rng(&world.rules);
+    let mut order_id_generator = IdGenerator::new();
+    let mut micro_ticks = 0;
+    world.config.use_order_commitment = true;
+    let mut ctx = Context {
+        config: &world.config,
+        rng: &mut rng,
+        order_id_generator: &mut order_id_generator,
+        micro_ticks: &mut micro_ticks,
+    };
+
+    let previous = Order::try_play(&world.me, &world, &[], f64::MAX.into(), &mut ctx);
+    let idle = Order::idle(&world.me, &world, ctx.order_id_generator);
+    let result = Order::commit(Some(&previous), idle, &world.me, &world, &[], f64::MAX.into(), &mut ctx);
+
+    assert_eq!(result.id(), previous.id());
+    assert_eq!(result.score(), 1268);
+    assert_eq!(result.action(), previous.action());
+}
+
+#[test]
+fn test_try_block_lane() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::vec3::Vec3;
+    use my_strategy::my_strategy::orders::Order;
+    use my_strategy::my_strategy::common::IdGenerator;
+    use my_strategy::my_strategy::blackboard::Blackboard;
+
+    let mut world = example_world(GameType::TwoRobots);
+    let mut order_id_generator = IdGenerator::new();
+
+    world.game.ball.set_position(Vec3::new(0.0, world.rules.BALL_RADIUS, -10.0));
+    world.me.set_position(Vec3::new(5.0, world.rules.ROBOT_RADIUS, -25.0));
+    world.game.robots.iter_mut()
+        .find(|v| !v.is_teammate)
+        .map(|v| v.set_position(Vec3::new(0.0, 1.0, -5.0)));
+
+    let result = Order::try_block_lane(&world.me, &world, &Blackboard::new(), std::f64::MAX.into(), &mut order_id_generator);
+
+    assert_eq!(result.action().target_velocity(), Vec3::new(-30.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_try_block_lane_uses_predicted_ball() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::vec3::Vec3;
+    use my_strategy::my_strategy::orders::Order;
+    use my_strategy::my_strategy::common::IdGenerator;
+    use my_strategy::my_strategy::blackboard::Blackboard;
+
+    let mut world = example_world(GameType::TwoRobots);
+    let mut order_id_generator = IdGenerator::new();
+
+    world.game.ball.set_position(Vec3::new(0.0, world.rules.BALL_RADIUS, -10.0));
+    world.game.ball.set_velocity(Vec3::new(10.0, 0.0, 0.0));
+    world.me.set_position(Vec3::new(5.0, world.rules.ROBOT_RADIUS, -25.0));
+    world.game.robots.iter_mut()
+        .find(|v| !v.is_teammate)
+        .map(|v| v.set_position(Vec3::new(0.0, 1.0, -5.0)));
+
+    let result = Order::try_block_lane(&world.me, &world, &Blackboard::new(), std::f64::MAX.into(), &mut order_id_generator);
+
+    assert_eq!(result.action().target_velocity(), Vec3::new(-20.41379310344829, 0.0, 1.7011494252872694));
+
+    world.game.robots.iter_mut()
+        .find(|v| !v.is_teammate)
+        .map(|v| v.set_position(Vec3::new(0.0, 1.0, -15.0)));
+
+    let result = Order::try_block_lane(&world.me, &world, &Blackboard::new(), std::f64::MAX.into(), &mut order_id_generator);
+
+    assert!(result.is_idle());
+}
+
+#[test]
+fn test_try_block_lane_uses_opponent_plan() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::vec3::Vec3;
+    use my_strategy::my_strategy::orders::Order;
+    use my_strategy::my_strategy::common::IdGenerator;
+    use my_strategy::my_strategy::blackboard::Blackboard;
+
+    let mut world = example_world(GameType::TwoRobots);
+    let mut order_id_generator = IdGenerator::new();
+    let mut blackboard = Blackboard::new();
+
+    world.game.ball.set_position(Vec3::new(0.0, world.rules.BALL_RADIUS, -10.0));
+    world.game.ball.set_velocity(Vec3::new(10.0, 0.0, 0.0));
+    world.me.set_position(Vec3::new(5.0, world.rules.ROBOT_RADIUS, -25.0));
+    let opponent_id = world.game.robots.iter_mut()
+        .find(|v| !v.is_teammate)
+        .map(|v| {
+            v.set_position(Vec3::new(0.0, 1.0, -5.0));
+            v.id
+        })
+        .unwrap();
+    blackboard.set_opponent_plan(opponent_id, Some(0.0));
+
+    let result = Order::try_block_lane(&world.me, &world, &blackboard, std::f64::MAX.into(), &mut order_id_generator);
+
+    assert_eq!(result.action().target_velocity(), Vec3::new(-30.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_try_block_lane_skips_lane_blocked_by_teammate() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::vec3::Vec3;
+    use my_strategy::my_strategy::orders::Order;
+    use my_strategy::my_strategy::common::IdGenerator;
+    use my_strategy::my_strategy::blackboard::Blackboard;
+
+    let mut world = example_world(GameType::TwoRobots);
+    let mut order_id_generator = IdGenerator::new();
+    let mut blackboard = Blackboard::new();
+
+    world.game.ball.set_position(Vec3::new(0.0, world.rules.BALL_RADIUS, -10.0));
+    world.me.set_position(Vec3::new(5.0, world.rules.ROBOT_RADIUS, -25.0));
+    world.game.robots.iter_mut()
+        .find(|v| !v.is_teammate)
+        .map(|v| v.set_position(Vec3::new(0.0, 1.0, -5.0)));
+    let teammate = world.game.robots.iter()
+        .find(|v| v.is_teammate && v.id != world.me.id)
+        .cloned()
+        .unwrap();
+
+    let order = Order::try_block_lane(&teammate, &world, &blackboard, std::f64::MAX.into(), &mut order_id_generator);
+    assert!(!order.is_idle());
+    blackboard.add_order(&order, &world);
+
+    let result = Order::try_block_lane(&world.me, &world, &blackboard, std::f64::MAX.into(), &mut order_id_generator);
+
+    assert!(result.is_idle());
+}
+
+#[test]
+fn test_try_mark() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::vec3::Vec3;
+    use my_strategy::my_strategy::orders::Order;
+    use my_strategy::my_strategy::common::IdGenerator;
+    use my_strategy::my_strategy::blackboard::Blackboard;
+
+    let mut world = example_world(GameType::ThreeRobotsWithNitro);
+    let mut order_id_generator = IdGenerator::new();
+
+    world.game.ball.set_position(Vec3::new(0.0, world.rules.BALL_RADIUS, 10.0));
+    world.me.set_position(Vec3::new(0.0, world.rules.ROBOT_RADIUS, -20.0));
+    let mut opponents = world.game.robots.iter_mut().filter(|v| !v.is_teammate);
+    opponents.next().map(|v| v.set_position(Vec3::new(0.0, 1.0, 12.0)));
+    opponents.next().map(|v| {
+        v.set_position(Vec3::new(10.0, 1.0, 0.0));
+        v.set_velocity(Vec3::new(0.0, 0.0, -10.0));
+    });
+    opponents.next().map(|v| v.set_position(Vec3::new(-10.0, 1.0, 15.0)));
+
+    let result = Order::try_mark(&world.me, &world, &Blackboard::new(), std::f64::MAX.into(), &mut order_id_generator);
+
+    assert_eq!(result.action().target_velocity(), Vec3::new(18.328003693955722, 0.0, 23.75045853440193));
+    match result {
+        Order::Mark(v) => assert_eq!(v.opponent_id, 5),
+        _ => panic!("expected mark order"),
+    }
+}
+
+#[test]
+fn test_try_mark_keeps_previous_opponent() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::vec3::Vec3;
+    use my_strategy::my_strategy::orders::Order;
+    use my_strategy::my_strategy::common::IdGenerator;
+    use my_strategy::my_strategy::blackboard::Blackboard;
+
+    let mut world = example_world(GameType::ThreeRobotsWithNitro);
+    let mut order_id_generator = IdGenerator::new();
+
+    world.game.ball.set_position(Vec3::new(0.0, world.rules.BALL_RADIUS, 10.0));
+    world.me.set_position(Vec3::new(0.0, world.rules.ROBOT_RADIUS, -20.0));
+    let mut opponents = world.game.robots.iter_mut().filter(|v| !v.is_teammate);
+    opponents.next().map(|v| v.set_position(Vec3::new(0.0, 1.0, 12.0)));
+    opponents.next().map(|v| {
+        v.set_position(Vec3::new(10.0, 1.0, 0.0));
+        v.set_velocity(Vec3::new(0.0, 0.0, -10.0));
+    });
+    opponents.next().map(|v| v.set_position(Vec3::new(-10.0, 1.0, 15.0)));
+
+    let mut blackboard = Blackboard::new();
+    blackboard.set_previous_marks(vec![(world.me.id, 6)]);
+
+    match Order::try_mark(&world.me, &world, &blackboard, std::f64::MAX.into(), &mut order_id_generator) {
+        Order::Mark(v) => assert_eq!(v.opponent_id, 6),
+        _ => panic!("expected mark order"),
+    }
+
+    blackboard.set_previous_marks(vec![(world.me.id, 4)]);
+
+    match Order::try_mark(&world.me, &world, &blackboard, std::f64::MAX.into(), &mut order_id_generator) {
+        Order::Mark(v) => assert_eq!(v.opponent_id, 5),
+        _ => panic!("expected mark order"),
+    }
+}
+
+#[test]
+fn test_commit_suppresses_play_take_nitro_pack_flip_flop() {
+    use my_strategy::examples::{GameType, example_world, example_rng};
+    use my_strategy::my_strategy::orders::{Order, Context};
+    use my_strategy::my_strategy::common::IdGenerator;
+    use my_strategy::my_strategy::blackboard::Blackboard;
+    use my_strategy::my_strategy::simulator::Simulator;
+
+    let mut world = example_world(GameType::TwoRobotsWithNitro);
+    world.config.use_order_commitment = true;
+    world.me.nitro_amount = 0.0;
+    let mut rng = example_rng(&world.rules);
+    let mut order_id_generator = IdGenerator::new();
+    let mut micro_ticks = 0;
+    let mut simulator = Simulator::new(&world, world.me.id);
+    let blackboard = Blackboard::new();
+
+    let mut previous = {
+        let mut ctx = Context {
+            config: &world.config,
+            rng: &mut rng,
+            order_id_generator: &mut order_id_generator,
+            micro_ticks: &mut micro_ticks,
+        };
+        Order::try_play(&world.me, &world, &[], std::f64::MAX.into(), &mut ctx)
+    };
+    let play_id = previous.id();
+    let mut scores = Vec::new();
+
+    for _ in 0..10 {
+        *simulator.me_mut().action_mut() = previous.action().clone();
+        simulator.tick(simulator.rules().tick_time_interval(), simulator.rules().MICROTICKS_PER_TICK, &mut rng);
+        world.update(simulator.me().base(), &simulator.game());
+
+        let take_nitro_pack = Order::try_take_nitro_pack(&world.me, &world, &blackboard, std::f64::MAX.into(),
+                                                         &mut order_id_generator);
+
+        assert!(match &take_nitro_pack { Order::TakeNitroPack(_) => true, _ => false });
+
+        let mut ctx = Context {
+            config: &world.config,
+            rng: &mut rng,
+            order_id_generator: &mut order_id_generator,
+            micro_ticks: &mut micro_ticks,
+        };
+        let result = Order::commit(Some(&previous), take_nitro_pack, &world.me, &world, &[], f64::MAX.into(), &mut ctx);
+
+        assert!(match &result { Order::Play(_) => true, _ => false });
+        assert_eq!(result.id(), play_id);
+
+        scores.push(result.score());
+        previous = result;
+    }
+
+    assert_eq!(scores, vec![1312, 1327, 1335, 1358, 1353, 1361, 1373, 1394, 1400, 1401]);
+}
+
+#[test]
+fn test_commit_keeps_fallback_when_play_is_not_better_by_margin() {
+    use my_strategy::examples::{GameType, example_world, example_rng};
+    use my_strategy::my_strategy::orders::{Order, Context};
+    use my_strategy::my_strategy::common::IdGenerator;
+    use my_strategy::my_strategy::blackboard::Blackboard;
+
+    let mut world = example_world(GameType::TwoRobotsWithNitro);
+    world.config.use_order_commitment = true;
+    world.me.nitro_amount = 0.0;
+    let mut rng = example_rng(&world.rules);
+    let mut order_id_generator = IdGenerator::new();
+    let mut micro_ticks = 0;
+    let previous = Order::try_take_nitro_pack(&world.me, &world, &Blackboard::new(), f64::MAX.into(),
+                                              &mut order_id_generator);
+    let mut ctx = Context {
+        config: &world.config,
+        rng: &mut rng,
+        order_id_generator: &mut order_id_generator,
+        micro_ticks: &mut micro_ticks,
+    };
+    let play_with_score = |score: i32, ctx: &mut Context| match Order::try_play(&world.me, &world, &[], f64::MAX.into(), ctx) {
+        Order::Play(mut v) => {
+            v.score = score;
+            Order::Play(v)
+        },
+        _ => panic!("expected play order"),
+    };
+
+    assert!(matches!(&previous, Order::TakeNitroPack(_)));
+
+    let play = play_with_score(world.config.order_commitment_margin, &mut ctx);
+    let result = Order::commit(Some(&previous), play, &world.me, &world, &[], f64::MAX.into(), &mut ctx);
+
+    assert!(result.is_idle());
+
+    let play = play_with_score(world.config.order_commitment_margin + 1, &mut ctx);
+    let play_id = play.id();
+    let result = Order::commit(Some(&previous), play, &world.me, &world, &[], f64::MAX.into(), &mut ctx);
+
+    assert_eq!(result.id(), play_id);
+}
+
+#[test]
+fn test_commit_keeps_jump_when_play_actions_are_over() {
+    use my_strategy::examples::{GameType, example_world, example_rng};
+    use my_strategy::my_strategy::orders::{Order, Context};
+    use my_strategy::my_strategy::common::IdGenerator;
+    use my_strategy::my_strategy::vec3::Vec3;
+    use my_strategy::model::Action;
+
+    let mut world = example_world(GameType::TwoRobots);
+    let mut rng = example_rng(&world.rules);
+    let mut order_id_generator = IdGenerator::new();
+    let mut micro_ticks = 0;
+    world.config.use_order_commitment = true;
+
+    let mut action = Action::default();
+    action.jump_speed = world.rules.ROBOT_MAX_JUMP_SPEED;
+    let previous = Order::follow_kickoff(&world.me, &world, &[action.clone()], &mut order_id_generator);
+
+    world.game.current_tick += 5;
+    world.me.set_position(Vec3::new(0.0, 3.0, -10.0));
+    world.me.set_velocity(Vec3::new(0.0, 10.0, 0.0));
+
+    let mut ctx = Context {
+        config: &world.config,
+        rng: &mut rng,
+        order_id_generator: &mut order_id_generator,
+        micro_ticks: &mut micro_ticks,
+    };
+    let idle = Order::idle(&world.me, &world, ctx.order_id_generator);
+    let result = Order::commit(Some(&previous), idle, &world.me, &world, &[], f64::MAX.into(), &mut ctx);
+
+    assert_eq!(result.id(), previous.id());
+    assert_eq!(result.action(), &action);
+    assert_eq!(micro_ticks, 0);
+}
+
+#[test]
+fn test_try_play_joint_forward_with_goalkeeper() {
+    use my_strategy::examples::{GameType, example_world, example_rng};
+    use my_strategy::my_strategy::vec3::Vec3;
+    use my_strategy::my_strategy::orders::{Order, Context};
+    use my_strategy::my_strategy::common::IdGenerator;
+    use my_strategy::my_strategy::roles::Goalkeeper;
+    use my_strategy::my_strategy::zone::Zone;
+
+    let mut world = example_world(GameType::TwoRobots);
+    world.me.set_position(Vec3::new(0.0, 1.0, -25.0));
+    world.game.ball.set_position(Vec3::new(0.0, 2.0, -20.0));
+    world.game.ball.set_velocity(Vec3::new(0.0, 0.0, -10.0));
+    let me_id = world.me.id;
+    let goalkeeper = world.game.robots.iter_mut()
+        .find(|v| v.is_teammate && v.id != me_id)
+        .map(|v| {
+            v.set_position(Vec3::new(-3.0, 1.0, -30.0));
+            v.clone()
+        })
+        .unwrap();
+    let goalkeeper_zone = Zone::from(Goalkeeper::max_z(&world));
+    let mut rng = example_rng(&world.rules);
+    let mut order_id_generator = IdGenerator::new();
+    let mut micro_ticks = 0;
+    let mut ctx = Context {
+        config: &world.config,
+        rng: &mut rng,
+        order_id_generator: &mut order_id_generator,
+        micro_ticks: &mut micro_ticks,
+    };
+
+    let (order, partner_order) = Order::try_play_joint(&world.me, &goalkeeper, &world, &[], std::f64::MAX.into(),
+                                                       goalkeeper_zone, &mut ctx);
+
+    assert!(match &order { Order::Play(_) => true, _ => false });
+    assert_eq!(order.score(), 1244);
+    assert_eq!(order.action().target_velocity(), Vec3::new(-21.052393739013148, 0.0, -17.7721467595412));
+    let partner_order = partner_order.unwrap();
+    assert_eq!(partner_order.robot_id(), goalkeeper.id);
+    assert_eq!(partner_order.action().target_velocity(), Vec3::new(8.620436566990362, 0.0, 28.73478855663454));
+    assert_eq!(micro_ticks, 20306);
+}
+
+#[test]
+fn test_try_play_in_opposite_world_ignores_opponent_model() {
+    use my_strategy::examples::{GameType, example_world, example_rng};
+    use my_strategy::my_strategy::vec3::Vec3;
+    use my_strategy::my_strategy::orders::{Order, Context};
+    use my_strategy::my_strategy::common::IdGenerator;
+    use my_strategy::my_strategy::world::World;
+    use my_strategy::my_strategy::learning::{Model, Layer, Activation};
+    use my_strategy::my_strategy::opponent_model::OpponentModel;
+
+    fn try_play(world: &World) -> i32 {
+        let mut rng = example_rng(&world.rules);
+        let mut order_id_generator = IdGenerator::new();
+        let mut micro_ticks = 0;
+        let mut ctx = Context {
+            config: &world.config,
+            rng: &mut rng,
+            order_id_generator: &mut order_id_generator,
+            micro_ticks: &mut micro_ticks,
+        };
+        Order::try_play(&world.me, world, &[], std::f64::MAX.into(), &mut ctx).score()
+    }
+
+    let mut world = example_world(GameType::TwoRobots);
+    world.game.ball.set_position(Vec3::new(0.0, 2.0, -5.0));
+    let opponent = world.game.robots.iter().find(|v| !v.is_teammate).unwrap().opposite();
+    let mut opposite = world.opposite();
+    opposite.me = opponent.clone();
+    let without_model = (try_play(&world), try_play(&opposite));
+
+    world.config.opponent_model = Some(OpponentModel {
+        intercept: Model {
+            layers: vec![Layer {
+                weights: vec![vec![0.0, 0.0, 0.0, 0.0, 0.0, 0.0]],
+                biases: vec![10.0],
+                activation: Activation::Sigmoid,
+            }],
+        },
+        speed_factor: 1.0,
+        jump_distance: world.rules.ball_distance_limit(),
+    });
+    let mut opposite = world.opposite();
+    opposite.me = opponent;
+
+    assert!(opposite.is_opposite());
+    assert_ne!(try_play(&world), without_model.0);
+    assert_eq!(try_play(&opposite), without_model.1);
+}
@@ -0,0 +1,177 @@
+#[test]
+fn test_search_with_jump_refinement_returns_refined_transitions() {
+    use my_strategy::examples::{GameType, example_world, example_rng};
+    use my_strategy::model::Action;
+    use my_strategy::my_strategy::plan::{Plan, Result, Transition};
+    use my_strategy::my_strategy::simulator::Simulator;
+    use my_strategy::my_strategy::vec3::Vec3;
+    use my_strategy::my_strategy::config::Config;
+    use my_strategy::my_strategy::world::World;
+
+    fn search(world: &World, config: &Config) -> Result {
+        let mut rng = example_rng(&world.rules);
+        let get_robot_action_at = |_: i32, _: i32| -> Option<&Action> { None };
+        Plan::new(
+            config,
+            world.game.current_tick,
+            0,
+            Simulator::new(world, world.me.id),
+            0.0,
+            std::f64::MAX.into(),
+            get_robot_action_at,
+            config.max_plan_micro_ticks,
+        ).search(&mut rng)
+    }
+
+    fn get_walk_target(result: &Result) -> Vec3 {
+        match &result.transitions[4] {
+            Transition::WalkToPosition(v) => v.target,
+            v => panic!("expected walk to position but got {:?}", v),
+        }
+    }
+
+    let world = example_world(GameType::TwoRobots);
+    let mut config = world.config.clone();
+    config.max_jump_refinement_iterations = 0;
+    let coarse = search(&world, &config);
+    config.max_jump_refinement_iterations = 20;
+    let refined = search(&world, &config);
+
+    assert_eq!(coarse.score, 1227);
+    assert_eq!(refined.score, 1256);
+    assert_eq!(
+        refined.transitions.iter().map(|v| v.name()).collect::<Vec<_>>(),
+        vec!["observe", "observe", "observe", "fork_ball", "walk_to_position", "jump", "watch_me_jump", "watch_ball_move"]
+    );
+    assert_eq!(get_walk_target(&coarse), Vec3::new(1.2837466947831617, 1.05, -2.2996537663972973));
+    assert_eq!(get_walk_target(&refined), Vec3::new(0.44088618380922495, 1.05, -2.333200719986689));
+    assert_eq!(format!("{:?}", &refined.transitions[5..]), format!("{:?}", &coarse.transitions[5..]));
+}
+
+#[test]
+fn test_search_with_jump_refinement_keeps_chosen_nitro_jump() {
+    use my_strategy::examples::{GameType, example_world, example_rng};
+    use my_strategy::model::Action;
+    use my_strategy::my_strategy::plan::{Plan, Result};
+    use my_strategy::my_strategy::simulator::Simulator;
+    use my_strategy::my_strategy::vec3::Vec3;
+    use my_strategy::my_strategy::config::Config;
+    use my_strategy::my_strategy::world::World;
+
+    fn search(world: &World, config: &Config) -> Result {
+        let mut rng = example_rng(&world.rules);
+        let get_robot_action_at = |_: i32, _: i32| -> Option<&Action> { None };
+        Plan::new(
+            config,
+            world.game.current_tick,
+            0,
+            Simulator::new(world, world.me.id),
+            0.0,
+            std::f64::MAX.into(),
+            get_robot_action_at,
+            config.max_plan_micro_ticks,
+        ).search(&mut rng)
+    }
+
+    fn get_tail(result: &Result) -> Vec<String> {
+        let walk_to_position = result.transitions.iter()
+            .position(|v| v.name() == "walk_to_position")
+            .unwrap();
+        result.transitions[walk_to_position + 1..].iter()
+            .map(|v| format!("{:?}", v))
+            .collect()
+    }
+
+    let mut world = example_world(GameType::TwoRobotsWithNitro);
+    world.config.use_aerial_control = true;
+    world.game.ball.set_position(Vec3::new(0.0, 4.0, -5.0));
+    let mut config = world.config.clone();
+    config.max_jump_refinement_iterations = 0;
+    let coarse = search(&world, &config);
+    config.max_jump_refinement_iterations = 20;
+    let refined = search(&world, &config);
+
+    assert_eq!(coarse.score, 1341);
+    assert_eq!(refined.score, 1417);
+    assert_eq!(get_tail(&refined), get_tail(&coarse));
+    assert_eq!(get_tail(&refined)[0], "Jump(Jump { target: Ball, allow_nitro: true })");
+}
+
+#[test]
+fn test_search_with_threat_penalty_respects_plan_micro_ticks_limit() {
+    use my_strategy::examples::{GameType, example_world, example_rng};
+    use my_strategy::model::Action;
+    use my_strategy::my_strategy::plan::Plan;
+    use my_strategy::my_strategy::simulator::Simulator;
+    use my_strategy::my_strategy::vec3::Vec3;
+
+    let mut world = example_world(GameType::TwoRobots);
+    world.config.threat_penalty_weight = 1.0;
+    world.game.ball.set_position(Vec3::new(0.0, 3.0, 12.0));
+    let mut rng = example_rng(&world.rules);
+    let get_robot_action_at = |_: i32, _: i32| -> Option<&Action> { None };
+    let max_plan_micro_ticks = 1000;
+
+    let result = Plan::new(
+        &world.config,
+        world.game.current_tick,
+        0,
+        Simulator::new(&world, world.me.id),
+        0.0,
+        std::f64::MAX.into(),
+        get_robot_action_at,
+        max_plan_micro_ticks,
+    ).search(&mut rng);
+
+    assert_eq!(result.used_micro_ticks, 999);
+    assert!(result.used_micro_ticks <= max_plan_micro_ticks);
+}
+
+#[test]
+fn test_plan_value_with_pass_is_scaled_by_pass_score() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::model::Action;
+    use my_strategy::my_strategy::plan::{Plan, Pass};
+    use my_strategy::my_strategy::simulator::Simulator;
+    use my_strategy::my_strategy::vec3::Vec3;
+
+    let world = example_world(GameType::TwoRobots);
+    let teammate = world.game.robots.iter().find(|v| v.is_teammate && v.id != world.me.id).unwrap();
+    let get_robot_action_at = |_: i32, _: i32| -> Option<&Action> { None };
+    let mut plan = Plan::new(
+        &world.config,
+        world.game.current_tick,
+        0,
+        Simulator::new(&world, world.me.id),
+        0.0,
+        std::f64::MAX.into(),
+        get_robot_action_at,
+        world.config.max_plan_micro_ticks,
+    );
+    let pass = Pass { robot_id: teammate.id, target: Vec3::new(teammate.x, world.rules.BALL_RADIUS, teammate.z) };
+    let max_time = (world.config.max_ticks + 1) as f64 * world.rules.tick_time_interval();
+    let pass_score = plan.get_pass_score(&pass, max_time);
+    plan.pass = Some(pass);
+
+    assert_eq!(pass_score, 0.5203038976917306);
+    assert_eq!(plan.get_value(), 0.5298863843938955);
+}
+
+#[test]
+fn test_get_points_aims_at_target() {
+    use my_strategy::examples::{GameType, example_world, example_rng};
+    use my_strategy::my_strategy::plan::get_points;
+    use my_strategy::my_strategy::simulator::Simulator;
+    use my_strategy::my_strategy::vec3::Vec3;
+
+    let world = example_world(GameType::TwoRobots);
+    let mut rng = example_rng(&world.rules);
+    let simulator = Simulator::new(&world, world.me.id);
+    let target = Vec3::new(-20.0, world.rules.BALL_RADIUS, 10.0);
+    let ball_position = world.game.ball.position();
+
+    let points = get_points(&simulator, Some(target), &world.config, 0, &mut rng);
+
+    assert_eq!(points[0], Vec3::new(1.3640014662748716, 1.05, -0.6820007331374358));
+    assert!((points[0] - ball_position).with_y(0.0).dot((target - ball_position).with_y(0.0)) < 0.0);
+}
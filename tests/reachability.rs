@@ -0,0 +1,90 @@
+use my_strategy::my_strategy::vec3::Vec3;
+use my_strategy::my_strategy::reachability::ReachabilityTable;
+use my_strategy::training::Grid;
+use my_strategy::examples::{GameType, example_world};
+
+fn make_table() -> ReachabilityTable {
+    let world = example_world(GameType::OneRobotWithNitro);
+    let grid = Grid {
+        speeds: vec![0.0, 15.0, 30.0],
+        forward: vec![-20.0, -10.0, 0.0, 10.0, 20.0],
+        lateral: vec![0.0, 10.0, 20.0],
+        heights: vec![0.0, 1.0, 2.0, 3.0, 4.0],
+        micro_ticks_per_tick: 10,
+        max_ticks: 300,
+    };
+    ReachabilityTable::generate(&world, &grid)
+}
+
+#[test]
+fn test_reachability_table_get_time_from_rest() {
+    let table = make_table();
+
+    assert_eq!(table.get_time(Vec3::default(), Vec3::new(0.0, 0.0, 10.0)), Some(0.44999999999999996));
+    assert_eq!(table.get_time(Vec3::default(), Vec3::new(0.0, 0.0, 30.0)), Some(1.1166666666666674));
+}
+
+#[test]
+fn test_reachability_table_get_time_depends_on_velocity_direction() {
+    let table = make_table();
+    let to_target = Vec3::new(0.0, 0.0, 15.0);
+
+    assert_eq!(table.get_time(Vec3::new(0.0, 0.0, 20.0), to_target), Some(0.502777777777778));
+    assert_eq!(table.get_time(Vec3::new(0.0, 0.0, -20.0), to_target), Some(0.9000000000000008));
+    assert_eq!(table.get_time(Vec3::new(20.0, 0.0, 0.0), to_target), Some(0.7055555555555562));
+}
+
+#[test]
+fn test_reachability_table_get_time_with_jump() {
+    let table = make_table();
+
+    assert_eq!(table.get_time(Vec3::default(), Vec3::new(0.0, 2.5, 1.0)), Some(0.22499999999999998));
+    assert_eq!(table.get_time(Vec3::default(), Vec3::new(0.0, 5.0, 1.0)), None);
+}
+
+#[test]
+fn test_reachability_table_deserialize_validates_table() {
+    let table = make_table();
+    let json = serde_json::to_string(&table).unwrap();
+    let restored: ReachabilityTable = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(restored.get_time(Vec3::default(), Vec3::new(0.0, 0.0, 10.0)), Some(0.45));
+
+    let mut empty = table.clone();
+    empty.heights = Vec::new();
+    empty.jump_times = Vec::new();
+
+    assert!(serde_json::from_str::<ReachabilityTable>(&serde_json::to_string(&empty).unwrap()).is_err());
+
+    let mut unsorted = table.clone();
+    unsorted.speeds = vec![0.0, 30.0, 15.0];
+
+    assert!(serde_json::from_str::<ReachabilityTable>(&serde_json::to_string(&unsorted).unwrap()).is_err());
+
+    let mut inconsistent = table.clone();
+    inconsistent.ground_times.pop();
+
+    assert!(serde_json::from_str::<ReachabilityTable>(&serde_json::to_string(&inconsistent).unwrap()).is_err());
+}
+
+#[test]
+fn test_reachability_table_get_time_with_invalid_table() {
+    let mut table = make_table();
+    table.heights = vec![0.0];
+    table.jump_times = vec![0.0];
+    table.lateral = Vec::new();
+
+    assert!(table.validate().is_err());
+    assert_eq!(table.get_time(Vec3::default(), Vec3::new(0.0, 2.5, 1.0)), None);
+}
+
+#[test]
+fn test_config_json_reachability_table() {
+    use my_strategy::my_strategy::config::Config;
+
+    let config: Config = serde_json::from_str(include_str!("../etc/config.json")).unwrap();
+    let table = config.reachability_table.as_ref().unwrap();
+
+    assert_eq!(table.get_time(Vec3::default(), Vec3::new(0.0, 0.0, 10.0)), Some(0.44999999999999996));
+    assert_eq!(table.get_time(Vec3::default(), Vec3::new(0.0, 2.5, 1.0)), Some(0.21666666666666665));
+}
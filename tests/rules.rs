@@ -0,0 +1,69 @@
+#[test]
+fn test_goalkeeper_coverage_position_for_ball_in_center() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::vec3::Vec3;
+
+    let world = example_world(GameType::TwoRobots);
+    let position = world.rules.get_goalkeeper_coverage_position(
+        Vec3::new(0.0, world.rules.BALL_RADIUS, -10.0),
+        Vec3::default(),
+        40.0,
+        0.1,
+    );
+
+    assert_eq!(position, Vec3::new(0.0, 1.0, -35.0));
+}
+
+#[test]
+fn test_goalkeeper_coverage_position_for_ball_at_side() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::vec3::Vec3;
+
+    let world = example_world(GameType::TwoRobots);
+    let position = world.rules.get_goalkeeper_coverage_position(
+        Vec3::new(-20.0, world.rules.BALL_RADIUS, -25.0),
+        Vec3::new(0.0, 0.0, -10.0),
+        40.0,
+        0.1,
+    );
+
+    assert_eq!(position, Vec3::new(-8.0, 1.0, -36.0));
+}
+
+#[test]
+fn test_is_shot_covered() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::vec3::Vec3;
+
+    let world = example_world(GameType::TwoRobots);
+    let origin = Vec3::new(0.0, world.rules.BALL_RADIUS, -20.0);
+    let target = Vec3::new(0.0, world.rules.BALL_RADIUS, -world.rules.arena.depth / 2.0);
+
+    assert!(world.rules.is_shot_covered(origin, target, 40.0, Vec3::new(0.0, 1.0, -35.0)));
+    assert!(!world.rules.is_shot_covered(origin, target, 40.0, Vec3::new(12.0, 1.0, -35.0)));
+}
+
+#[test]
+fn test_jump_height_time_with_nitro() {
+    use my_strategy::examples::{GameType, example_world};
+
+    let world = example_world(GameType::OneRobotWithNitro);
+
+    assert_eq!(world.rules.jump_height_time_with_nitro(1.0, 0.0), Some(0.0));
+    assert_eq!(world.rules.jump_height_time_with_nitro(3.0, 0.0), Some(0.15358983848622454));
+    assert_eq!(world.rules.jump_height_time_with_nitro(6.0, 0.0), None);
+    assert_eq!(world.rules.jump_height_time_with_nitro(6.0, 10.0), Some(0.3535898384862246));
+    assert_eq!(world.rules.jump_height_time_with_nitro(6.0, world.rules.MAX_NITRO_AMOUNT), Some(0.33));
+}
+
+#[test]
+fn test_get_target_velocity() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::vec3::Vec3;
+
+    let world = example_world(GameType::OneRobotWithNitro);
+
+    assert_eq!(world.rules.get_target_velocity(Vec3::default()), Vec3::default());
+    assert_eq!(world.rules.get_target_velocity(Vec3::new(0.0, 10.0, 0.0)), Vec3::new(0.0, 30.0, 0.0));
+    assert_eq!(world.rules.get_target_velocity(Vec3::new(0.0, 0.0, 1e-3)), Vec3::new(0.0, 0.0, 0.006666666666666666));
+}
@@ -0,0 +1,14 @@
+#[test]
+fn test_get_aerial_reach() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::scenarios::get_aerial_reach;
+
+    let world = example_world(GameType::OneRobotWithNitro);
+
+    assert_eq!(get_aerial_reach(1.0, 0.0, &world.rules), Some(3.05));
+    assert_eq!(get_aerial_reach(3.0, 0.0, &world.rules), Some(7.657695154586736));
+    assert_eq!(get_aerial_reach(6.0, 0.0, &world.rules), None);
+    assert_eq!(get_aerial_reach(6.0, world.rules.MAX_NITRO_AMOUNT, &world.rules), Some(12.95));
+    assert_eq!(get_aerial_reach(12.0, world.rules.MAX_NITRO_AMOUNT, &world.rules), Some(24.95));
+    assert_eq!(get_aerial_reach(40.0, world.rules.MAX_NITRO_AMOUNT, &world.rules), None);
+}
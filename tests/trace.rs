@@ -0,0 +1,37 @@
+#[cfg(feature = "enable_trace")]
+#[test]
+fn test_trace_top_alternatives() {
+    use my_strategy::my_strategy::trace::{Trace, Node};
+
+    let node = |id: i32, parent_id: Option<i32>, transition: Option<&str>, state: &str, score: i32| Node {
+        id,
+        parent_id,
+        transition: transition.map(|v| v.to_string()),
+        state: state.to_string(),
+        score,
+        time: 0.0,
+        error: None,
+    };
+
+    let trace = Trace {
+        player_id: 1,
+        robot_id: 1,
+        current_tick: 0,
+        order_id: 1,
+        final_state_id: Some(4),
+        nodes: vec![
+            node(1, None, None, "Initial", 0),
+            node(2, Some(1), Some("far_jump"), "End", 900),
+            node(3, Some(1), Some("observe"), "ObservedBall", 1000),
+            node(4, Some(3), Some("fork_ball"), "End", 1200),
+        ],
+    };
+
+    let top = trace.get_top_alternatives(5);
+
+    assert_eq!(top.iter().map(|v| v.id).collect::<Vec<_>>(), vec![4, 2]);
+    assert_eq!(
+        trace.get_path(4).iter().filter_map(|v| v.transition.clone()).collect::<Vec<_>>(),
+        vec!["observe".to_string(), "fork_ball".to_string()]
+    );
+}
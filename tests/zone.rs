@@ -0,0 +1,50 @@
+#[test]
+fn test_zone_contains() {
+    use my_strategy::my_strategy::zone::Zone;
+    use my_strategy::my_strategy::vec2::Vec2;
+    use my_strategy::my_strategy::vec3::Vec3;
+
+    let zone = Zone::from(-10.0);
+
+    assert!(zone.contains(Vec3::new(0.0, 1.0, -11.0)));
+    assert!(!zone.contains(Vec3::new(0.0, 1.0, -5.0)));
+
+    let zone = zone.with_extension(Vec2::new(0.0, -5.0), 2.0);
+
+    assert!(zone.contains(Vec3::new(0.0, 1.0, -5.0)));
+    assert!(!zone.contains(Vec3::new(3.0, 1.0, -5.0)));
+}
+
+#[test]
+fn test_goalkeeper_zone_for_slow_ball() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::roles::Goalkeeper;
+    use my_strategy::my_strategy::vec3::Vec3;
+
+    let mut world = example_world(GameType::TwoRobots);
+    world.config.use_dynamic_goalkeeper_zone = true;
+    world.game.ball.set_position(Vec3::new(0.0, world.rules.BALL_RADIUS, -8.0));
+    world.game.ball.set_velocity(Vec3::new(0.0, 0.0, -2.0));
+    let goalkeeper = Goalkeeper { robot_id: world.me.id };
+    let zone = goalkeeper.zone(&world);
+
+    assert_eq!(zone.max_z(), Goalkeeper::max_z(&world));
+    assert!(zone.contains(Vec3::new(0.0, 1.0, -8.0)));
+}
+
+#[test]
+fn test_goalkeeper_zone_for_fast_ball() {
+    use my_strategy::examples::{GameType, example_world};
+    use my_strategy::my_strategy::roles::Goalkeeper;
+    use my_strategy::my_strategy::vec3::Vec3;
+
+    let mut world = example_world(GameType::TwoRobots);
+    world.config.use_dynamic_goalkeeper_zone = true;
+    world.game.ball.set_position(Vec3::new(0.0, world.rules.BALL_RADIUS, -8.0));
+    world.game.ball.set_velocity(Vec3::new(0.0, 0.0, -20.0));
+    let goalkeeper = Goalkeeper { robot_id: world.me.id };
+    let zone = goalkeeper.zone(&world);
+
+    assert_eq!(zone.max_z(), Goalkeeper::max_z(&world));
+    assert!(!zone.contains(Vec3::new(0.0, 1.0, -8.0)));
+}